@@ -0,0 +1,87 @@
+//! Compares the batch APIs in `range_date::bucket` with calling
+//! `DatePeriod::from_date_as_month` once per date.
+//!
+//! Run with `cargo bench --bench bucketize`.
+
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use chrono::NaiveDate;
+use range_date::bucket::{bucketize, count_by_period, group_sorted};
+use range_date::range_type::{DatePeriod, PeriodKind};
+
+const DATES: usize = 1_000_000;
+const ROUNDS: u32 = 10;
+
+/// Deterministic pseudo-random dates spread over roughly 30 years.
+fn sample_dates(count: usize) -> Vec<NaiveDate> {
+    let base = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap_or_default();
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    (0..count)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            base + chrono::Duration::days(((state >> 33) % 11_000) as i64)
+        })
+        .collect()
+}
+
+/// Run `f` `ROUNDS` times and report the best time per date.
+fn bench<T>(name: &str, dates: &[NaiveDate], mut f: impl FnMut(&[NaiveDate]) -> T) {
+    let mut best = Duration::MAX;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        black_box(f(black_box(dates)));
+        best = best.min(start.elapsed());
+    }
+    println!(
+        "{:<40} {:>8.2} ns/date",
+        name,
+        best.as_nanos() as f64 / dates.len() as f64
+    );
+}
+
+fn main() {
+    let unsorted = sample_dates(DATES);
+    let mut sorted = unsorted.clone();
+    sorted.sort();
+
+    for (label, dates) in [("unsorted", &unsorted), ("sorted", &sorted)] {
+        println!("-- {} dates ({}) --", DATES, label);
+        bench("from_date_as_month loop", dates, |dates| {
+            dates
+                .iter()
+                .map(|d| DatePeriod::from_date_as_month(*d))
+                .collect::<anyhow::Result<Vec<_>>>()
+        });
+        bench("bucketize(Month)", dates, |dates| {
+            bucketize(dates, PeriodKind::Month)
+        });
+        bench("from_date_as_month counts", dates, |dates| {
+            let mut counts = BTreeMap::new();
+            for date in dates {
+                *counts
+                    .entry(DatePeriod::from_date_as_month(*date)?)
+                    .or_insert(0usize) += 1;
+            }
+            anyhow::Ok(counts)
+        });
+        bench("count_by_period(Month)", dates, |dates| {
+            count_by_period(dates, PeriodKind::Month)
+        });
+    }
+
+    println!("-- {} dates (sorted, streaming) --", DATES);
+    bench("group_sorted(Month)", &sorted, |dates| {
+        group_sorted(dates, PeriodKind::Month)
+            .map(|group| group.map(|(_, dates)| dates.len()))
+            .sum::<anyhow::Result<usize>>()
+    });
+    bench("group_sorted(Daily)", &sorted, |dates| {
+        group_sorted(dates, PeriodKind::Daily)
+            .map(|group| group.map(|(_, dates)| dates.len()))
+            .sum::<anyhow::Result<usize>>()
+    });
+}
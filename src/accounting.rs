@@ -0,0 +1,896 @@
+//! 13-period (4-week) accounting calendars.
+//!
+//! Retailers and other businesses close their books on thirteen periods of
+//! four weeks each. A 364-day year drifts against the calendar, so every five
+//! or six years a leap week is added to one of the periods. An
+//! [`AccountingCalendar`] describes when years start and which period gets
+//! the leap week; [`AccountingPeriod`] is a year, period or week of such a
+//! calendar with the hierarchy and navigation API of [`DatePeriod`].
+//!
+//! ```rust
+//! use range_date::accounting::{AccountingCalendar, AccountingKind, YearStart};
+//! use chrono::{NaiveDate, Weekday};
+//!
+//! // Years start on the Sunday nearest January 1st; the leap week goes to
+//! // period 13
+//! let calendar = AccountingCalendar::new(Weekday::Sun, 1, 1, YearStart::Nearest, 13).unwrap();
+//! let date = NaiveDate::from_ymd_opt(2024, 2, 14).unwrap();
+//! let period = calendar.from_date(date, AccountingKind::Period).unwrap();
+//! assert_eq!(period.to_string(), "2024P2@SUN~01-01/L13");
+//! assert_eq!(period.get_first_day().unwrap(), NaiveDate::from_ymd_opt(2024, 1, 28).unwrap());
+//! assert_eq!(period.aggregate().decompose().len(), 13);
+//! assert!(calendar.is_leap_year(2025).unwrap());
+//! ```
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::period::Period;
+use crate::range_type::DatePeriod;
+
+/// Number of periods in an accounting year
+const PERIODS: u32 = 13;
+
+/// How an accounting year's first day is chosen relative to its anchor date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum YearStart {
+    /// The start weekday on or before the anchor date
+    OnOrBefore,
+    /// The start weekday on or after the anchor date
+    OnOrAfter,
+    /// The start weekday nearest the anchor date
+    Nearest,
+}
+
+impl YearStart {
+    fn symbol(&self) -> char {
+        match self {
+            YearStart::OnOrBefore => '<',
+            YearStart::OnOrAfter => '>',
+            YearStart::Nearest => '~',
+        }
+    }
+}
+
+/// The rules of a 13-period accounting calendar
+///
+/// Year `N` starts on `start_weekday` chosen by `rule` around the anchor
+/// date `start_month`/`start_day` of calendar year `N`, and ends the day
+/// before year `N + 1` starts. Years therefore have 52 weeks, or 53 in leap
+/// years, and the leap week is added at the end of `leap_week_period`.
+///
+/// The textual form is `<weekday><rule><MM>-<DD>/L<leap period>`, where the
+/// rule is `<` (on or before), `>` (on or after) or `~` (nearest), e.g.
+/// `SUN~01-01/L13`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AccountingCalendar {
+    start_weekday: Weekday,
+    start_month: u32,
+    start_day: u32,
+    rule: YearStart,
+    leap_week_period: u32,
+}
+
+impl AccountingCalendar {
+    /// Create a calendar
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `start_month`/`start_day` is not a day of every
+    /// year (February 29th is rejected) or `leap_week_period` is not in
+    /// `1..=13`.
+    pub fn new(
+        start_weekday: Weekday,
+        start_month: u32,
+        start_day: u32,
+        rule: YearStart,
+        leap_week_period: u32,
+    ) -> anyhow::Result<Self> {
+        if NaiveDate::from_ymd_opt(2023, start_month, start_day).is_none() {
+            return Err(anyhow::anyhow!(
+                "Invalid year start anchor: {:02}-{:02}",
+                start_month,
+                start_day
+            ));
+        }
+        if !(1..=PERIODS).contains(&leap_week_period) {
+            return Err(anyhow::anyhow!(
+                "Leap week period must be 1-{}, got: {}",
+                PERIODS,
+                leap_week_period
+            ));
+        }
+        Ok(AccountingCalendar {
+            start_weekday,
+            start_month,
+            start_day,
+            rule,
+            leap_week_period,
+        })
+    }
+
+    /// Get the accounting year `year`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the year lies outside
+    /// [`AccountingPeriod::MIN_YEAR`]`..=`[`AccountingPeriod::MAX_YEAR`].
+    pub fn year(&self, year: u32) -> anyhow::Result<AccountingPeriod> {
+        self.build(AccountingKind::Year, year, year)
+    }
+
+    /// Get period `period` (1-13) of the accounting year `year`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `period` is not in `1..=13` or the year is out of
+    /// range.
+    pub fn period(&self, year: u32, period: u32) -> anyhow::Result<AccountingPeriod> {
+        if !(1..=PERIODS).contains(&period) {
+            return Err(anyhow::anyhow!(
+                "Period must be 1-{}, got: {}",
+                PERIODS,
+                period
+            ));
+        }
+        self.build(AccountingKind::Period, year, period)
+    }
+
+    /// Get week `week` of the accounting year `year`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the year is out of range or `week` is not in
+    /// `1..=52` (`1..=53` in leap years).
+    pub fn week(&self, year: u32, week: u32) -> anyhow::Result<AccountingPeriod> {
+        let weeks = self.weeks_in_year(year)?;
+        if !(1..=weeks).contains(&week) {
+            return Err(anyhow::anyhow!(
+                "Week must be 1-{} in accounting year {}, got: {}",
+                weeks,
+                year,
+                week
+            ));
+        }
+        self.build(AccountingKind::Week, year, week)
+    }
+
+    /// Get the number of weeks (52 or 53) in the accounting year `year`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the year is out of range.
+    pub fn weeks_in_year(&self, year: u32) -> anyhow::Result<u32> {
+        let (first_day, last_day) = self
+            .year_bounds(year)
+            .ok_or_else(|| AccountingPeriod::out_of_range(year))?;
+        Ok(((last_day - first_day).num_days() as u32 + 1) / 7)
+    }
+
+    /// Check if the accounting year `year` has a leap week
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the year is out of range.
+    pub fn is_leap_year(&self, year: u32) -> anyhow::Result<bool> {
+        Ok(self.weeks_in_year(year)? == 53)
+    }
+
+    /// Get the accounting period of the given kind containing `date`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `date` lies outside the supported years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::accounting::{AccountingCalendar, AccountingKind, YearStart};
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// // Fiscal years start on the Monday on or before September 1st
+    /// let calendar = AccountingCalendar::new(Weekday::Mon, 9, 1, YearStart::OnOrBefore, 12).unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+    /// let week = calendar.from_date(date, AccountingKind::Week).unwrap();
+    /// assert_eq!(week.get_year(), 2024);
+    /// assert_eq!(week.value(), 19);
+    /// ```
+    pub fn from_date(
+        &self,
+        date: NaiveDate,
+        kind: AccountingKind,
+    ) -> anyhow::Result<AccountingPeriod> {
+        let out_of_range =
+            || anyhow::anyhow!("Date {} is outside the supported accounting years", date);
+        let mut year = date.year();
+        while self.year_start(year).is_some_and(|start| date < start) {
+            year -= 1;
+        }
+        while self.year_start(year + 1).is_some_and(|start| date >= start) {
+            year += 1;
+        }
+        let year = u32::try_from(year).map_err(|_| out_of_range())?;
+        let (first_day, _) = self.year_bounds(year).ok_or_else(out_of_range)?;
+        let week = ((date - first_day).num_days() / 7) as u32 + 1;
+        match kind {
+            AccountingKind::Year => self.year(year),
+            AccountingKind::Period => self.period(year, self.period_of_week(year, week)?),
+            AccountingKind::Week => self.week(year, week),
+        }
+    }
+
+    /// Generate all accounting periods of `kind` between two dates
+    /// (inclusive)
+    /// Returns an empty vector if start > end
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either date lies outside the supported years.
+    pub fn between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        kind: AccountingKind,
+    ) -> anyhow::Result<Vec<AccountingPeriod>> {
+        if start > end {
+            return Ok(vec![]);
+        }
+        let mut current = self.from_date(start, kind)?;
+        let last = self.from_date(end, kind)?;
+        let mut periods = vec![current];
+        while current < last {
+            current = current.succ()?;
+            periods.push(current);
+        }
+        Ok(periods)
+    }
+
+    /// Get the weekday every accounting week starts on
+    pub fn start_weekday(&self) -> Weekday {
+        self.start_weekday
+    }
+
+    /// Get the period that receives the leap week
+    pub fn leap_week_period(&self) -> u32 {
+        self.leap_week_period
+    }
+
+    /// Total order over calendars, as [`Weekday`] is not [`Ord`]
+    fn sort_key(&self) -> (u32, u32, u32, YearStart, u32) {
+        (
+            self.start_weekday.num_days_from_monday(),
+            self.start_month,
+            self.start_day,
+            self.rule,
+            self.leap_week_period,
+        )
+    }
+
+    fn build(
+        &self,
+        kind: AccountingKind,
+        year: u32,
+        value: u32,
+    ) -> anyhow::Result<AccountingPeriod> {
+        if year > AccountingPeriod::MAX_YEAR || self.year_bounds(year).is_none() {
+            return Err(AccountingPeriod::out_of_range(year));
+        }
+        Ok(AccountingPeriod {
+            calendar: *self,
+            kind,
+            year,
+            value,
+        })
+    }
+
+    /// First day of the accounting year whose anchor lies in `year`
+    fn year_start(&self, year: i32) -> Option<NaiveDate> {
+        let anchor = NaiveDate::from_ymd_opt(year, self.start_month, self.start_day)?;
+        let weekday = anchor.weekday().num_days_from_monday() as i64;
+        let target = self.start_weekday.num_days_from_monday() as i64;
+        let before = (weekday - target).rem_euclid(7);
+        let offset = match self.rule {
+            YearStart::OnOrBefore => -before,
+            YearStart::OnOrAfter => (target - weekday).rem_euclid(7),
+            YearStart::Nearest if before <= 3 => -before,
+            YearStart::Nearest => 7 - before,
+        };
+        anchor.checked_add_signed(Duration::try_days(offset)?)
+    }
+
+    /// First and last day of the accounting year `year`
+    fn year_bounds(&self, year: u32) -> Option<(NaiveDate, NaiveDate)> {
+        let year = i32::try_from(year).ok()?;
+        Some((
+            self.year_start(year)?,
+            self.year_start(year + 1)?.pred_opt()?,
+        ))
+    }
+
+    /// First week and number of weeks of `period` in `year`
+    fn period_weeks(&self, year: u32, period: u32) -> anyhow::Result<(u32, u32)> {
+        let leap = self.is_leap_year(year)?;
+        let first_week = 4 * (period - 1) + 1 + u32::from(leap && period > self.leap_week_period);
+        let weeks = 4 + u32::from(leap && period == self.leap_week_period);
+        Ok((first_week, weeks))
+    }
+
+    fn period_of_week(&self, year: u32, week: u32) -> anyhow::Result<u32> {
+        for period in 1..=PERIODS {
+            let (first_week, weeks) = self.period_weeks(year, period)?;
+            if week < first_week + weeks {
+                return Ok(period);
+            }
+        }
+        Err(anyhow::anyhow!(
+            "Week {} is not in accounting year {}",
+            week,
+            year
+        ))
+    }
+}
+
+impl std::fmt::Display for AccountingCalendar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{:02}-{:02}/L{}",
+            self.start_weekday.to_string().to_ascii_uppercase(),
+            self.rule.symbol(),
+            self.start_month,
+            self.start_day,
+            self.leap_week_period
+        )
+    }
+}
+
+impl std::str::FromStr for AccountingCalendar {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || anyhow::anyhow!("Invalid accounting calendar format: {}", s);
+        let s = s.trim();
+        let position = s.find(['<', '>', '~']).ok_or_else(invalid)?;
+        let weekday: Weekday = s[..position].parse().map_err(|_| invalid())?;
+        let rule = match &s[position..position + 1] {
+            "<" => YearStart::OnOrBefore,
+            ">" => YearStart::OnOrAfter,
+            _ => YearStart::Nearest,
+        };
+        let (anchor, leap) = s[position + 1..].split_once("/L").ok_or_else(invalid)?;
+        let (month, day) = anchor.split_once('-').ok_or_else(invalid)?;
+        let number = |digits: &str| {
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            digits.parse::<u32>().map_err(|_| invalid())
+        };
+        Self::new(weekday, number(month)?, number(day)?, rule, number(leap)?)
+    }
+}
+
+/// The granularity of an [`AccountingPeriod`], ordered from coarsest to
+/// finest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AccountingKind {
+    Year,
+    Period,
+    Week,
+}
+
+impl AccountingKind {
+    /// Get the short name used in period strings (`Y`, `P` or `W`)
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            AccountingKind::Year => "Y",
+            AccountingKind::Period => "P",
+            AccountingKind::Week => "W",
+        }
+    }
+
+    /// Get the next coarser kind, `None` for [`AccountingKind::Year`]
+    pub fn parent(&self) -> Option<AccountingKind> {
+        match self {
+            AccountingKind::Year => None,
+            AccountingKind::Period => Some(AccountingKind::Year),
+            AccountingKind::Week => Some(AccountingKind::Period),
+        }
+    }
+
+    /// Get the next finer kind, `None` for [`AccountingKind::Week`]
+    pub fn child(&self) -> Option<AccountingKind> {
+        match self {
+            AccountingKind::Year => Some(AccountingKind::Period),
+            AccountingKind::Period => Some(AccountingKind::Week),
+            AccountingKind::Week => None,
+        }
+    }
+}
+
+/// A year, period or week of an [`AccountingCalendar`]
+///
+/// The textual form is the period followed by its calendar, e.g.
+/// `2024Y@SUN~01-01/L13`, `2024P3@SUN~01-01/L13` or `2024W9@SUN~01-01/L13`.
+/// Periods are built through the [`AccountingCalendar`] constructors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AccountingPeriod {
+    calendar: AccountingCalendar,
+    kind: AccountingKind,
+    year: u32,
+    value: u32,
+}
+
+impl AccountingPeriod {
+    /// The earliest supported accounting year
+    pub const MIN_YEAR: u32 = DatePeriod::MIN_YEAR;
+
+    /// The latest supported accounting year
+    ///
+    /// One less than [`DatePeriod::MAX_YEAR`], as the last day of a year is
+    /// found from the start of the next one.
+    pub const MAX_YEAR: u32 = DatePeriod::MAX_YEAR - 1;
+
+    /// Get the calendar the period belongs to
+    pub fn calendar(&self) -> AccountingCalendar {
+        self.calendar
+    }
+
+    /// Get the kind of the period
+    pub fn kind(&self) -> AccountingKind {
+        self.kind
+    }
+
+    /// Get the accounting year of the period
+    pub fn get_year(&self) -> u32 {
+        self.year
+    }
+
+    /// Get the period or week number; the year for yearly periods
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Get the first day of the period
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the day cannot be represented as a [`NaiveDate`].
+    pub fn get_first_day(&self) -> anyhow::Result<NaiveDate> {
+        self.bounds()
+            .map(|(first_day, _)| first_day)
+            .ok_or_else(|| Self::out_of_range(self.year))
+    }
+
+    /// Get the last day of the period (inclusive)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the day cannot be represented as a [`NaiveDate`].
+    pub fn get_last_day(&self) -> anyhow::Result<NaiveDate> {
+        self.bounds()
+            .map(|(_, last_day)| last_day)
+            .ok_or_else(|| Self::out_of_range(self.year))
+    }
+
+    /// Check if `date` lies within the period
+    pub fn contains_date(&self, date: NaiveDate) -> bool {
+        self.bounds()
+            .is_some_and(|(first_day, last_day)| first_day <= date && date <= last_day)
+    }
+
+    /// Get the next period of the same kind
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the next period lies after
+    /// [`AccountingPeriod::MAX_YEAR`].
+    pub fn succ(&self) -> anyhow::Result<Self> {
+        self.offset_n(1)
+    }
+
+    /// Get the previous period of the same kind
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the previous period lies before
+    /// [`AccountingPeriod::MIN_YEAR`].
+    pub fn pred(&self) -> anyhow::Result<Self> {
+        self.offset_n(-1)
+    }
+
+    /// Get the period `n` steps away, backwards if `n` is negative
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result lies outside the supported years.
+    pub fn offset_n(&self, n: i32) -> anyhow::Result<Self> {
+        self.checked_add(n as i64)
+            .ok_or_else(|| anyhow::anyhow!("{} offset by {} is out of range", self, n))
+    }
+
+    /// Get the period `n` steps away, `None` if out of range
+    pub fn checked_add(&self, n: i64) -> Option<Self> {
+        match self.kind {
+            AccountingKind::Year => {
+                let year = u32::try_from((self.year as i64).checked_add(n)?).ok()?;
+                self.calendar.year(year).ok()
+            }
+            AccountingKind::Period => {
+                let position = (self.year as i64 * PERIODS as i64)
+                    .checked_add(self.value as i64 - 1)?
+                    .checked_add(n)?;
+                let year = u32::try_from(position.div_euclid(PERIODS as i64)).ok()?;
+                let period = position.rem_euclid(PERIODS as i64) as u32 + 1;
+                self.calendar.period(year, period).ok()
+            }
+            AccountingKind::Week => {
+                let (first_day, _) = self.bounds()?;
+                let date = first_day.checked_add_signed(Duration::try_weeks(n)?)?;
+                self.calendar.from_date(date, AccountingKind::Week).ok()
+            }
+        }
+    }
+
+    /// Get the period of the next coarser kind containing this one
+    ///
+    /// Years have no parent and return themselves.
+    pub fn aggregate(&self) -> Self {
+        match self.kind {
+            AccountingKind::Year => *self,
+            AccountingKind::Period => AccountingPeriod {
+                kind: AccountingKind::Year,
+                value: self.year,
+                ..*self
+            },
+            AccountingKind::Week => self
+                .calendar
+                .period_of_week(self.year, self.value)
+                .map(|period| AccountingPeriod {
+                    kind: AccountingKind::Period,
+                    value: period,
+                    ..*self
+                })
+                .unwrap_or(*self),
+        }
+    }
+
+    /// Decompose the period into periods of the next finer kind
+    ///
+    /// A year yields its 13 periods, a period its 4 or 5 weeks, and a week
+    /// an empty vector.
+    pub fn decompose(&self) -> Vec<Self> {
+        let (kind, values) = match self.kind {
+            AccountingKind::Year => (AccountingKind::Period, 1..=PERIODS),
+            AccountingKind::Period => match self.calendar.period_weeks(self.year, self.value) {
+                Ok((first_week, weeks)) => {
+                    (AccountingKind::Week, first_week..=first_week + weeks - 1)
+                }
+                Err(_) => return vec![],
+            },
+            AccountingKind::Week => return vec![],
+        };
+        values
+            .map(|value| AccountingPeriod {
+                kind,
+                value,
+                ..*self
+            })
+            .collect()
+    }
+
+    /// First and last day, `None` if not representable
+    fn bounds(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let (year_start, year_end) = self.calendar.year_bounds(self.year)?;
+        let (first_week, weeks) = match self.kind {
+            AccountingKind::Year => return Some((year_start, year_end)),
+            AccountingKind::Period => self.calendar.period_weeks(self.year, self.value).ok()?,
+            AccountingKind::Week => (self.value, 1),
+        };
+        let first_day =
+            year_start.checked_add_signed(Duration::try_weeks(first_week as i64 - 1)?)?;
+        let last_day = first_day.checked_add_signed(Duration::try_days(weeks as i64 * 7 - 1)?)?;
+        Some((first_day, last_day))
+    }
+
+    fn out_of_range(year: u32) -> anyhow::Error {
+        anyhow::anyhow!(
+            "Accounting year must be {}-{}, got: {}",
+            Self::MIN_YEAR,
+            Self::MAX_YEAR,
+            year
+        )
+    }
+}
+
+/// Orders periods of one calendar like [`DatePeriod`]: by kind, then
+/// chronologically
+impl PartialOrd for AccountingPeriod {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AccountingPeriod {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.kind, self.year, self.value)
+            .cmp(&(other.kind, other.year, other.value))
+            .then_with(|| self.calendar.sort_key().cmp(&other.calendar.sort_key()))
+    }
+}
+
+impl std::fmt::Display for AccountingPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            AccountingKind::Year => write!(f, "{}Y@{}", self.year, self.calendar),
+            kind => write!(
+                f,
+                "{}{}{}@{}",
+                self.year,
+                kind.short_name(),
+                self.value,
+                self.calendar
+            ),
+        }
+    }
+}
+
+impl std::str::FromStr for AccountingPeriod {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || anyhow::anyhow!("Invalid accounting period format: {}", s);
+        let (period, calendar) = s.trim().split_once('@').ok_or_else(invalid)?;
+        let calendar: AccountingCalendar = calendar.parse()?;
+        let position = period.find(['Y', 'P', 'W']).ok_or_else(invalid)?;
+        let digits = |digits: &str| {
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            digits.parse::<u32>().map_err(|_| invalid())
+        };
+        let year = digits(&period[..position])?;
+        let value = &period[position + 1..];
+        match &period[position..position + 1] {
+            "Y" if value.is_empty() => calendar.year(year),
+            "P" => calendar.period(year, digits(value)?),
+            "W" => calendar.week(year, digits(value)?),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl Serialize for AccountingPeriod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountingPeriod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<AccountingPeriod, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use std::str::FromStr;
+        let s = String::deserialize(deserializer)?;
+        AccountingPeriod::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Period for AccountingPeriod {
+    type Parent = AccountingPeriod;
+    type Child = AccountingPeriod;
+
+    fn get_first_day(&self) -> anyhow::Result<NaiveDate> {
+        AccountingPeriod::get_first_day(self)
+    }
+
+    fn get_last_day(&self) -> anyhow::Result<NaiveDate> {
+        AccountingPeriod::get_last_day(self)
+    }
+
+    fn checked_add(&self, n: i64) -> Option<Self> {
+        AccountingPeriod::checked_add(self, n)
+    }
+
+    fn containing(&self, date: NaiveDate) -> anyhow::Result<Self> {
+        self.calendar.from_date(date, self.kind)
+    }
+
+    fn parent(&self) -> Option<Self> {
+        self.kind.parent().map(|_| self.aggregate())
+    }
+
+    fn children(&self) -> Vec<Self> {
+        self.decompose()
+    }
+
+    fn contains_date(&self, date: NaiveDate) -> bool {
+        AccountingPeriod::contains_date(self, date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn calendar() -> AccountingCalendar {
+        AccountingCalendar::new(Weekday::Sun, 1, 1, YearStart::Nearest, 13).unwrap()
+    }
+
+    #[test]
+    fn test_year_start_rules() {
+        // January 1st 2025 is a Wednesday
+        let cases = [
+            (YearStart::OnOrBefore, date(2024, 12, 29)),
+            (YearStart::OnOrAfter, date(2025, 1, 5)),
+            (YearStart::Nearest, date(2024, 12, 29)),
+        ];
+        for (rule, first_day) in cases {
+            let calendar = AccountingCalendar::new(Weekday::Sun, 1, 1, rule, 13).unwrap();
+            assert_eq!(
+                calendar.year(2025).unwrap().get_first_day().unwrap(),
+                first_day
+            );
+            assert_eq!(first_day.weekday(), Weekday::Sun);
+        }
+        let monday = AccountingCalendar::new(Weekday::Mon, 1, 1, YearStart::Nearest, 13).unwrap();
+        assert_eq!(
+            monday.year(2025).unwrap().get_first_day().unwrap(),
+            date(2024, 12, 30)
+        );
+
+        assert!(AccountingCalendar::new(Weekday::Sun, 2, 29, YearStart::Nearest, 13).is_err());
+        assert!(AccountingCalendar::new(Weekday::Sun, 1, 1, YearStart::Nearest, 14).is_err());
+    }
+
+    #[test]
+    fn test_leap_weeks() {
+        let calendar = calendar();
+        let leap_years: Vec<u32> = (2000..2040)
+            .filter(|year| calendar.is_leap_year(*year).unwrap())
+            .collect();
+        assert_eq!(leap_years, [2003, 2008, 2014, 2020, 2025, 2031, 2036]);
+
+        for leap_week_period in [1, 7, 13] {
+            let calendar =
+                AccountingCalendar::new(Weekday::Sun, 1, 1, YearStart::Nearest, leap_week_period)
+                    .unwrap();
+            let periods = calendar.year(2025).unwrap().decompose();
+            let weeks: Vec<usize> = periods.iter().map(|p| p.decompose().len()).collect();
+            assert_eq!(weeks.iter().sum::<usize>(), 53);
+            assert_eq!(weeks[leap_week_period as usize - 1], 5);
+            assert_eq!(weeks.iter().filter(|w| **w == 5).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_periods_tile_the_calendar() {
+        let calendar =
+            AccountingCalendar::new(Weekday::Sat, 9, 1, YearStart::OnOrAfter, 6).unwrap();
+        for kind in [
+            AccountingKind::Year,
+            AccountingKind::Period,
+            AccountingKind::Week,
+        ] {
+            let mut day = date(2018, 6, 1);
+            let mut previous = calendar.from_date(day, kind).unwrap();
+            while day < date(2022, 6, 1) {
+                let period = calendar.from_date(day, kind).unwrap();
+                assert!(period.contains_date(day));
+                if period != previous {
+                    assert_eq!(previous.succ().unwrap(), period);
+                    assert_eq!(period.pred().unwrap(), previous);
+                    assert_eq!(period.get_first_day().unwrap(), day);
+                    assert_eq!(day.weekday(), Weekday::Sat);
+                    assert!(period.aggregate().contains_date(day));
+                    assert!(
+                        period.aggregate().decompose().contains(&period)
+                            || kind == AccountingKind::Year
+                    );
+                }
+                previous = period;
+                day = day.succ_opt().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_navigation_and_ranges() {
+        let calendar = calendar();
+        let period = calendar.period(2024, 13).unwrap();
+        assert_eq!(period.succ().unwrap(), calendar.period(2025, 1).unwrap());
+        assert_eq!(
+            period.offset_n(-13).unwrap(),
+            calendar.period(2023, 13).unwrap()
+        );
+        let week = calendar.week(2025, 53).unwrap();
+        assert_eq!(week.succ().unwrap(), calendar.week(2026, 1).unwrap());
+        assert_eq!(week.aggregate(), calendar.period(2025, 13).unwrap());
+
+        let periods = calendar
+            .between(date(2023, 12, 30), date(2024, 3, 1), AccountingKind::Period)
+            .unwrap();
+        assert_eq!(
+            periods.iter().map(|p| p.value()).collect::<Vec<_>>(),
+            [13, 1, 2, 3]
+        );
+        assert_eq!(periods[0].get_year(), 2023);
+        let generic: Vec<_> =
+            crate::period::between(&periods[0], date(2023, 12, 30), date(2024, 3, 1))
+                .unwrap()
+                .collect();
+        assert_eq!(generic, periods);
+        assert!(
+            calendar
+                .between(date(2024, 3, 1), date(2024, 1, 1), AccountingKind::Week)
+                .unwrap()
+                .is_empty()
+        );
+
+        assert!(
+            calendar
+                .year(AccountingPeriod::MIN_YEAR)
+                .unwrap()
+                .pred()
+                .is_err()
+        );
+        assert!(
+            calendar
+                .year(AccountingPeriod::MAX_YEAR)
+                .unwrap()
+                .succ()
+                .is_err()
+        );
+        assert!(calendar.year(AccountingPeriod::MAX_YEAR + 1).is_err());
+        assert_eq!(period.checked_add(i64::MAX), None);
+        assert!(calendar.period(2024, 14).is_err());
+        assert!(calendar.week(2024, 53).is_err());
+    }
+
+    #[test]
+    fn test_text_and_serde() {
+        let calendar =
+            AccountingCalendar::new(Weekday::Mon, 9, 1, YearStart::OnOrBefore, 12).unwrap();
+        assert_eq!(calendar.to_string(), "MON<09-01/L12");
+        assert_eq!(
+            "MON<09-01/L12".parse::<AccountingCalendar>().unwrap(),
+            calendar
+        );
+        for period in [
+            calendar.year(2024).unwrap(),
+            calendar.period(2024, 3).unwrap(),
+            calendar.week(2024, 9).unwrap(),
+        ] {
+            let text = period.to_string();
+            assert_eq!(text.parse::<AccountingPeriod>().unwrap(), period);
+            let json = serde_json::to_string(&period).unwrap();
+            assert_eq!(
+                serde_json::from_str::<AccountingPeriod>(&json).unwrap(),
+                period
+            );
+        }
+        assert_eq!(
+            calendar.period(2024, 3).unwrap().to_string(),
+            "2024P3@MON<09-01/L12"
+        );
+        for invalid in [
+            "2024P3",
+            "2024P14@MON<09-01/L12",
+            "2024Y1@MON<09-01/L12",
+            "2024X1@MON<09-01/L12",
+            "2024P3@XYZ<09-01/L12",
+            "2024P3@MON<09-01",
+            "2024P3@MON<13-01/L12",
+            "2024P3@MON<09-01/L0",
+        ] {
+            assert!(invalid.parse::<AccountingPeriod>().is_err(), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,798 @@
+//! The broadcast calendar used in media buying.
+//!
+//! Broadcast weeks run Monday to Sunday. A broadcast month starts on the
+//! Monday of the week containing the 1st of the calendar month and ends on
+//! the last Sunday of that month, so every broadcast month is exactly four or
+//! five weeks long. Broadcast quarters are three broadcast months, and the
+//! broadcast year starts on the Monday of the week containing January 1st.
+//!
+//! [`BroadcastPeriod`] mirrors the API of [`DatePeriod`]: validated
+//! constructors, first and last days, date-to-period mapping, navigation,
+//! aggregation and a string form (`2024BY`, `2024BQ1`, `2024BM3`, `2024BW5`).
+//!
+//! ```rust
+//! use range_date::broadcast::{BroadcastKind, BroadcastPeriod};
+//! use chrono::NaiveDate;
+//!
+//! // January 1st 2025 is a Wednesday, so broadcast 2025 starts on Monday
+//! // December 30th 2024
+//! let date = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+//! let month = BroadcastPeriod::from_date(date, BroadcastKind::Month).unwrap();
+//! assert_eq!(month.to_string(), "2025BM1");
+//! assert_eq!(month.get_first_day().unwrap(), date);
+//! assert_eq!(month.get_last_day().unwrap(), NaiveDate::from_ymd_opt(2025, 1, 26).unwrap());
+//! assert_eq!(month.weeks().len(), 4);
+//! assert_eq!(month.succ().unwrap().to_string(), "2025BM2");
+//! ```
+
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::period::Period;
+use crate::range_type::DatePeriod;
+
+/// The granularity of a [`BroadcastPeriod`], ordered from coarsest to finest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BroadcastKind {
+    Year,
+    Quarter,
+    Month,
+    Week,
+}
+
+impl BroadcastKind {
+    /// All kinds, from coarsest to finest
+    pub const ALL: [BroadcastKind; 4] = [
+        BroadcastKind::Year,
+        BroadcastKind::Quarter,
+        BroadcastKind::Month,
+        BroadcastKind::Week,
+    ];
+
+    /// Get the short name used in period strings (`Y`, `Q`, `M` or `W`)
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            BroadcastKind::Year => "Y",
+            BroadcastKind::Quarter => "Q",
+            BroadcastKind::Month => "M",
+            BroadcastKind::Week => "W",
+        }
+    }
+
+    /// Get the full upper-case name
+    pub fn name(&self) -> &'static str {
+        match self {
+            BroadcastKind::Year => "YEAR",
+            BroadcastKind::Quarter => "QUARTER",
+            BroadcastKind::Month => "MONTH",
+            BroadcastKind::Week => "WEEK",
+        }
+    }
+
+    /// Get the next coarser kind, `None` for [`BroadcastKind::Year`]
+    pub fn parent(&self) -> Option<BroadcastKind> {
+        match self {
+            BroadcastKind::Year => None,
+            BroadcastKind::Quarter => Some(BroadcastKind::Year),
+            BroadcastKind::Month => Some(BroadcastKind::Quarter),
+            BroadcastKind::Week => Some(BroadcastKind::Month),
+        }
+    }
+
+    /// Get the next finer kind, `None` for [`BroadcastKind::Week`]
+    pub fn child(&self) -> Option<BroadcastKind> {
+        match self {
+            BroadcastKind::Year => Some(BroadcastKind::Quarter),
+            BroadcastKind::Quarter => Some(BroadcastKind::Month),
+            BroadcastKind::Month => Some(BroadcastKind::Week),
+            BroadcastKind::Week => None,
+        }
+    }
+}
+
+impl std::fmt::Display for BroadcastKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for BroadcastKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "Y" | "YEAR" => Ok(BroadcastKind::Year),
+            "Q" | "QUARTER" => Ok(BroadcastKind::Quarter),
+            "M" | "MONTH" => Ok(BroadcastKind::Month),
+            "W" | "WEEK" => Ok(BroadcastKind::Week),
+            _ => Err(anyhow::anyhow!("Invalid broadcast kind: {}", s)),
+        }
+    }
+}
+
+/// A broadcast year, quarter, month or week
+///
+/// Years are labelled by the calendar year they mostly cover. Weeks are
+/// numbered from 1 within their broadcast year, which has 52 or 53 weeks.
+/// Periods can only be built through the validating constructors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum BroadcastPeriod {
+    #[non_exhaustive]
+    Year(u32),
+    #[non_exhaustive]
+    Quarter(u32, u32),
+    #[non_exhaustive]
+    Month(u32, u32),
+    #[non_exhaustive]
+    Week(u32, u32),
+}
+
+impl BroadcastPeriod {
+    /// The earliest supported broadcast year
+    pub const MIN_YEAR: u32 = DatePeriod::MIN_YEAR;
+
+    /// The latest supported broadcast year
+    ///
+    /// One less than [`DatePeriod::MAX_YEAR`], whose broadcast year would end
+    /// after the last date [`NaiveDate`] can represent.
+    pub const MAX_YEAR: u32 = DatePeriod::MAX_YEAR - 1;
+
+    /// Create a broadcast year with validation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `year` is greater than [`BroadcastPeriod::MAX_YEAR`].
+    pub fn year(year: u32) -> anyhow::Result<Self> {
+        Self::check_year(year)?;
+        Ok(BroadcastPeriod::Year(year))
+    }
+
+    /// Create a broadcast quarter with validation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `quarter` is not in `1..=4` or `year` is greater
+    /// than [`BroadcastPeriod::MAX_YEAR`].
+    pub fn quarter(year: u32, quarter: u32) -> anyhow::Result<Self> {
+        Self::check_year(year)?;
+        if !(1..=4).contains(&quarter) {
+            return Err(anyhow::anyhow!("Quarter must be 1-4, got: {}", quarter));
+        }
+        Ok(BroadcastPeriod::Quarter(year, quarter))
+    }
+
+    /// Create a broadcast month with validation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `month` is not in `1..=12` or `year` is greater
+    /// than [`BroadcastPeriod::MAX_YEAR`].
+    pub fn month(year: u32, month: u32) -> anyhow::Result<Self> {
+        Self::check_year(year)?;
+        if !(1..=12).contains(&month) {
+            return Err(anyhow::anyhow!("Month must be 1-12, got: {}", month));
+        }
+        Ok(BroadcastPeriod::Month(year, month))
+    }
+
+    /// Create a broadcast week with validation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `year` is greater than [`BroadcastPeriod::MAX_YEAR`]
+    /// or `week` is not a week of the broadcast year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::broadcast::BroadcastPeriod;
+    ///
+    /// assert!(BroadcastPeriod::week(2024, 52).is_ok());
+    /// assert!(BroadcastPeriod::week(2024, 53).is_err());
+    /// assert!(BroadcastPeriod::week(2023, 53).is_ok());
+    /// ```
+    pub fn week(year: u32, week: u32) -> anyhow::Result<Self> {
+        let weeks = Self::weeks_in_year(year)?;
+        if !(1..=weeks).contains(&week) {
+            return Err(anyhow::anyhow!(
+                "Week must be 1-{} in broadcast year {}, got: {}",
+                weeks,
+                year,
+                week
+            ));
+        }
+        Ok(BroadcastPeriod::Week(year, week))
+    }
+
+    /// Get the number of weeks (52 or 53) in the broadcast year `year`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `year` is greater than [`BroadcastPeriod::MAX_YEAR`].
+    pub fn weeks_in_year(year: u32) -> anyhow::Result<u32> {
+        let (first_day, last_day) = Self::year(year)?
+            .bounds()
+            .ok_or_else(|| Self::out_of_range(year))?;
+        Ok(((last_day - first_day).num_days() as u32 + 1) / 7)
+    }
+
+    /// Parse a broadcast period from its string form, e.g. `2024BM3`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not `<year>B<kind>[index]` or the
+    /// period fails validation.
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let invalid = || anyhow::anyhow!("Invalid broadcast period format: {}", s);
+        let s = s.trim();
+        let (year, rest) = s.split_once('B').ok_or_else(invalid)?;
+        if year.is_empty() || !year.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let year: u32 = year.parse().map_err(|_| invalid())?;
+        let kind = rest.get(0..1).ok_or_else(invalid)?;
+        let index = || {
+            let index = &rest[1..];
+            if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            index.parse::<u32>().map_err(|_| invalid())
+        };
+        match kind {
+            "Y" if rest.len() == 1 => Self::year(year),
+            "Q" => Self::quarter(year, index()?),
+            "M" => Self::month(year, index()?),
+            "W" => Self::week(year, index()?),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Create the broadcast period of the given kind containing `date`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `date` lies outside the supported broadcast years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::broadcast::{BroadcastKind, BroadcastPeriod};
+    /// use chrono::NaiveDate;
+    ///
+    /// // March 31st 2024 is a Sunday, the last day of broadcast March
+    /// let date = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+    /// let month = BroadcastPeriod::from_date(date, BroadcastKind::Month).unwrap();
+    /// assert_eq!(month.to_string(), "2024BM3");
+    /// let next = date.succ_opt().unwrap();
+    /// let quarter = BroadcastPeriod::from_date(next, BroadcastKind::Quarter).unwrap();
+    /// assert_eq!(quarter.to_string(), "2024BQ2");
+    /// ```
+    pub fn from_date(date: NaiveDate, kind: BroadcastKind) -> anyhow::Result<Self> {
+        let (year, month) = Self::year_month_of(date).ok_or_else(|| {
+            anyhow::anyhow!("Date {} is outside the supported broadcast years", date)
+        })?;
+        match kind {
+            BroadcastKind::Year => Self::year(year),
+            BroadcastKind::Quarter => Self::quarter(year, (month - 1) / 3 + 1),
+            BroadcastKind::Month => Self::month(year, month),
+            BroadcastKind::Week => {
+                let (first_day, _) = Self::year(year)?
+                    .bounds()
+                    .ok_or_else(|| Self::out_of_range(year))?;
+                Self::week(year, ((date - first_day).num_days() / 7) as u32 + 1)
+            }
+        }
+    }
+
+    /// Generate all broadcast periods of `kind` between two dates (inclusive)
+    /// Returns an empty vector if start > end
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either date lies outside the supported broadcast
+    /// years.
+    pub fn between(
+        start: NaiveDate,
+        end: NaiveDate,
+        kind: BroadcastKind,
+    ) -> anyhow::Result<Vec<BroadcastPeriod>> {
+        if start > end {
+            return Ok(vec![]);
+        }
+        let mut current = Self::from_date(start, kind)?;
+        let last = Self::from_date(end, kind)?;
+        let mut periods = vec![current];
+        while current < last {
+            current = current.succ()?;
+            periods.push(current);
+        }
+        Ok(periods)
+    }
+
+    /// Get the first day (a Monday) of the period
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the day cannot be represented as a [`NaiveDate`].
+    pub fn get_first_day(&self) -> anyhow::Result<NaiveDate> {
+        self.bounds()
+            .map(|(first_day, _)| first_day)
+            .ok_or_else(|| Self::out_of_range(self.get_year()))
+    }
+
+    /// Get the last day (a Sunday) of the period
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the day cannot be represented as a [`NaiveDate`].
+    pub fn get_last_day(&self) -> anyhow::Result<NaiveDate> {
+        self.bounds()
+            .map(|(_, last_day)| last_day)
+            .ok_or_else(|| Self::out_of_range(self.get_year()))
+    }
+
+    /// Check if `date` lies within the period
+    pub fn contains_date(&self, date: NaiveDate) -> bool {
+        self.bounds()
+            .is_some_and(|(first_day, last_day)| first_day <= date && date <= last_day)
+    }
+
+    /// Get the broadcast year of the period
+    pub fn get_year(&self) -> u32 {
+        match self {
+            BroadcastPeriod::Year(year)
+            | BroadcastPeriod::Quarter(year, _)
+            | BroadcastPeriod::Month(year, _)
+            | BroadcastPeriod::Week(year, _) => *year,
+        }
+    }
+
+    /// Get the quarter, month or week number; the year for yearly periods
+    pub fn value(&self) -> u32 {
+        match self {
+            BroadcastPeriod::Year(year) => *year,
+            BroadcastPeriod::Quarter(_, value)
+            | BroadcastPeriod::Month(_, value)
+            | BroadcastPeriod::Week(_, value) => *value,
+        }
+    }
+
+    /// Get the kind of the period
+    pub fn kind(&self) -> BroadcastKind {
+        match self {
+            BroadcastPeriod::Year(_) => BroadcastKind::Year,
+            BroadcastPeriod::Quarter(..) => BroadcastKind::Quarter,
+            BroadcastPeriod::Month(..) => BroadcastKind::Month,
+            BroadcastPeriod::Week(..) => BroadcastKind::Week,
+        }
+    }
+
+    /// Get the next period of the same kind
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the next period lies after
+    /// [`BroadcastPeriod::MAX_YEAR`].
+    pub fn succ(&self) -> anyhow::Result<Self> {
+        self.offset_n(1)
+    }
+
+    /// Get the previous period of the same kind
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the previous period lies before
+    /// [`BroadcastPeriod::MIN_YEAR`].
+    pub fn pred(&self) -> anyhow::Result<Self> {
+        self.offset_n(-1)
+    }
+
+    /// Get the period `n` steps ahead
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result lies outside the supported years.
+    pub fn succ_n(&self, n: u32) -> anyhow::Result<Self> {
+        self.checked_add(n as i64)
+            .ok_or_else(|| anyhow::anyhow!("{} plus {} is out of range", self, n))
+    }
+
+    /// Get the period `n` steps back
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result lies outside the supported years.
+    pub fn pred_n(&self, n: u32) -> anyhow::Result<Self> {
+        self.checked_sub(n as i64)
+            .ok_or_else(|| anyhow::anyhow!("{} minus {} is out of range", self, n))
+    }
+
+    /// Get the period `n` steps away, backwards if `n` is negative
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result lies outside the supported years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::broadcast::BroadcastPeriod;
+    ///
+    /// let week = BroadcastPeriod::week(2024, 52).unwrap();
+    /// assert_eq!(week.offset_n(1).unwrap().to_string(), "2025BW1");
+    /// let month = BroadcastPeriod::month(2024, 1).unwrap();
+    /// assert_eq!(month.offset_n(-1).unwrap().to_string(), "2023BM12");
+    /// ```
+    pub fn offset_n(&self, n: i32) -> anyhow::Result<Self> {
+        self.checked_add(n as i64)
+            .ok_or_else(|| anyhow::anyhow!("{} offset by {} is out of range", self, n))
+    }
+
+    /// Get the period `n` steps away, `None` if out of range
+    pub fn checked_add(&self, n: i64) -> Option<Self> {
+        let per_year = match self {
+            BroadcastPeriod::Week(..) => {
+                let (first_day, _) = self.bounds()?;
+                let date = first_day.checked_add_signed(Duration::try_weeks(n)?)?;
+                return Self::from_date(date, BroadcastKind::Week).ok();
+            }
+            BroadcastPeriod::Year(_) => 1,
+            BroadcastPeriod::Quarter(..) => 4,
+            BroadcastPeriod::Month(..) => 12,
+        };
+        let index = self.index_in_year();
+        let position = (self.get_year() as i64)
+            .checked_mul(per_year)?
+            .checked_add(index as i64 - 1)?
+            .checked_add(n)?;
+        let year = u32::try_from(position.div_euclid(per_year)).ok()?;
+        let value = position.rem_euclid(per_year) as u32 + 1;
+        match self {
+            BroadcastPeriod::Year(_) => Self::year(year),
+            BroadcastPeriod::Quarter(..) => Self::quarter(year, value),
+            _ => Self::month(year, value),
+        }
+        .ok()
+    }
+
+    /// Get the period `n` steps back, `None` if out of range
+    pub fn checked_sub(&self, n: i64) -> Option<Self> {
+        self.checked_add(n.checked_neg()?)
+    }
+
+    /// Get the period of the next coarser kind containing this one
+    ///
+    /// Years have no parent and return themselves.
+    pub fn aggregate(&self) -> Self {
+        match *self {
+            BroadcastPeriod::Year(_) => *self,
+            BroadcastPeriod::Quarter(year, _) => BroadcastPeriod::Year(year),
+            BroadcastPeriod::Month(year, month) => {
+                BroadcastPeriod::Quarter(year, (month - 1) / 3 + 1)
+            }
+            BroadcastPeriod::Week(..) => self
+                .bounds()
+                .and_then(|(first_day, _)| Self::from_date(first_day, BroadcastKind::Month).ok())
+                .unwrap_or(*self),
+        }
+    }
+
+    /// Decompose the period into periods of the next finer kind
+    ///
+    /// Weeks have no children and return an empty vector.
+    pub fn decompose(&self) -> Vec<Self> {
+        match *self {
+            BroadcastPeriod::Year(year) => {
+                (1..=4).map(|q| BroadcastPeriod::Quarter(year, q)).collect()
+            }
+            BroadcastPeriod::Quarter(year, quarter) => (quarter * 3 - 2..=quarter * 3)
+                .map(|month| BroadcastPeriod::Month(year, month))
+                .collect(),
+            BroadcastPeriod::Month(..) => self.weeks(),
+            BroadcastPeriod::Week(..) => vec![],
+        }
+    }
+
+    /// Get the broadcast weeks making up the period
+    pub fn weeks(&self) -> Vec<Self> {
+        let Some((first_day, last_day)) = self.bounds() else {
+            return vec![];
+        };
+        Self::between(first_day, last_day, BroadcastKind::Week).unwrap_or_default()
+    }
+
+    /// Quarter, month or week number; 1 for years
+    fn index_in_year(&self) -> u32 {
+        match self {
+            BroadcastPeriod::Year(_) => 1,
+            _ => self.value(),
+        }
+    }
+
+    /// First and last day, `None` if not representable
+    fn bounds(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let year = self.get_year() as i32;
+        let (first_day, next_start) = match *self {
+            BroadcastPeriod::Year(_) => (month_start(year, 1)?, month_start(year + 1, 1)?),
+            BroadcastPeriod::Quarter(_, quarter) => (
+                month_start(year, quarter * 3 - 2)?,
+                next_month_start(year, quarter * 3)?,
+            ),
+            BroadcastPeriod::Month(_, month) => {
+                (month_start(year, month)?, next_month_start(year, month)?)
+            }
+            BroadcastPeriod::Week(_, week) => {
+                let first_day = month_start(year, 1)?
+                    .checked_add_signed(Duration::try_weeks(week as i64 - 1)?)?;
+                (
+                    first_day,
+                    first_day.checked_add_signed(Duration::try_weeks(1)?)?,
+                )
+            }
+        };
+        Some((first_day, next_start.pred_opt()?))
+    }
+
+    /// Broadcast year and month containing `date`
+    fn year_month_of(date: NaiveDate) -> Option<(u32, u32)> {
+        let (mut year, mut month) = (date.year(), date.month());
+        if date >= next_month_start(year, month)? {
+            (year, month) = if month == 12 {
+                (year + 1, 1)
+            } else {
+                (year, month + 1)
+            };
+        }
+        let year = u32::try_from(year).ok()?;
+        (year <= Self::MAX_YEAR).then_some((year, month))
+    }
+
+    fn check_year(year: u32) -> anyhow::Result<()> {
+        if year > Self::MAX_YEAR {
+            return Err(Self::out_of_range(year));
+        }
+        Ok(())
+    }
+
+    fn out_of_range(year: u32) -> anyhow::Error {
+        anyhow::anyhow!(
+            "Broadcast year must be {}-{}, got: {}",
+            Self::MIN_YEAR,
+            Self::MAX_YEAR,
+            year
+        )
+    }
+}
+
+/// Monday of the week containing the 1st of `month`
+fn month_start(year: i32, month: u32) -> Option<NaiveDate> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    first.checked_sub_signed(Duration::days(first.weekday().num_days_from_monday() as i64))
+}
+
+/// Start of the broadcast month after `month`
+fn next_month_start(year: i32, month: u32) -> Option<NaiveDate> {
+    if month == 12 {
+        month_start(year + 1, 1)
+    } else {
+        month_start(year, month + 1)
+    }
+}
+
+impl std::fmt::Display for BroadcastPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BroadcastPeriod::Year(year) => write!(f, "{}BY", year),
+            _ => write!(
+                f,
+                "{}B{}{}",
+                self.get_year(),
+                self.kind().short_name(),
+                self.value()
+            ),
+        }
+    }
+}
+
+impl std::str::FromStr for BroadcastPeriod {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Serialize for BroadcastPeriod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BroadcastPeriod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<BroadcastPeriod, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        BroadcastPeriod::parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Period for BroadcastPeriod {
+    type Parent = BroadcastPeriod;
+    type Child = BroadcastPeriod;
+
+    fn get_first_day(&self) -> anyhow::Result<NaiveDate> {
+        BroadcastPeriod::get_first_day(self)
+    }
+
+    fn get_last_day(&self) -> anyhow::Result<NaiveDate> {
+        BroadcastPeriod::get_last_day(self)
+    }
+
+    fn checked_add(&self, n: i64) -> Option<Self> {
+        BroadcastPeriod::checked_add(self, n)
+    }
+
+    fn containing(&self, date: NaiveDate) -> anyhow::Result<Self> {
+        BroadcastPeriod::from_date(date, self.kind())
+    }
+
+    fn parent(&self) -> Option<Self> {
+        self.kind().parent().map(|_| self.aggregate())
+    }
+
+    fn children(&self) -> Vec<Self> {
+        self.decompose()
+    }
+
+    fn contains_date(&self, date: NaiveDate) -> bool {
+        BroadcastPeriod::contains_date(self, date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Weekday;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_known_broadcast_months() {
+        // Published broadcast calendar for 2024
+        let expected = [
+            (1, date(2024, 1, 1), date(2024, 1, 28)),
+            (2, date(2024, 1, 29), date(2024, 2, 25)),
+            (3, date(2024, 2, 26), date(2024, 3, 31)),
+            (4, date(2024, 4, 1), date(2024, 4, 28)),
+            (9, date(2024, 8, 26), date(2024, 9, 29)),
+            (12, date(2024, 11, 25), date(2024, 12, 29)),
+        ];
+        for (month, first_day, last_day) in expected {
+            let period = BroadcastPeriod::month(2024, month).unwrap();
+            assert_eq!(period.get_first_day().unwrap(), first_day, "{}", period);
+            assert_eq!(period.get_last_day().unwrap(), last_day, "{}", period);
+        }
+        let year = BroadcastPeriod::year(2025).unwrap();
+        assert_eq!(year.get_first_day().unwrap(), date(2024, 12, 30));
+        assert_eq!(year.get_last_day().unwrap(), date(2025, 12, 28));
+        assert_eq!(BroadcastPeriod::weeks_in_year(2024).unwrap(), 52);
+    }
+
+    #[test]
+    fn test_periods_tile_the_calendar() {
+        for kind in BroadcastKind::ALL {
+            let mut day = date(2019, 12, 1);
+            let mut previous = BroadcastPeriod::from_date(day, kind).unwrap();
+            while day < date(2027, 2, 1) {
+                let period = BroadcastPeriod::from_date(day, kind).unwrap();
+                assert!(period.contains_date(day));
+                if period != previous {
+                    assert_eq!(previous.succ().unwrap(), period);
+                    assert_eq!(period.pred().unwrap(), previous);
+                    assert_eq!(period.get_first_day().unwrap(), day);
+                    assert_eq!(day.weekday(), Weekday::Mon);
+                    assert_eq!(
+                        period.aggregate(),
+                        period.aggregate().containing(day).unwrap()
+                    );
+                }
+                if kind == BroadcastKind::Month {
+                    let weeks = period.weeks().len();
+                    assert!(weeks == 4 || weeks == 5, "{} has {} weeks", period, weeks);
+                }
+                previous = period;
+                day = day.succ_opt().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_aggregate_and_decompose() {
+        let year = BroadcastPeriod::year(2024).unwrap();
+        let months: Vec<_> = year
+            .decompose()
+            .iter()
+            .flat_map(BroadcastPeriod::decompose)
+            .collect();
+        assert_eq!(months.len(), 12);
+        let weeks: Vec<_> = months.iter().flat_map(BroadcastPeriod::decompose).collect();
+        assert_eq!(weeks, year.weeks());
+        assert_eq!(weeks.len(), 52);
+        for week in &weeks {
+            assert!(months.contains(&week.aggregate()));
+            assert_eq!(week.aggregate().aggregate().aggregate(), year);
+        }
+        assert!(weeks[0].decompose().is_empty());
+        assert_eq!(year.parent(), None);
+    }
+
+    #[test]
+    fn test_navigation_and_ranges() {
+        let month = BroadcastPeriod::month(2024, 11).unwrap();
+        assert_eq!(month.succ_n(2).unwrap().to_string(), "2025BM1");
+        assert_eq!(month.pred_n(11).unwrap().to_string(), "2023BM12");
+        let week = BroadcastPeriod::week(2023, 53).unwrap();
+        assert_eq!(week.succ().unwrap().to_string(), "2024BW1");
+        assert_eq!(week.offset_n(-53).unwrap().to_string(), "2022BW52");
+
+        let quarters =
+            BroadcastPeriod::between(date(2024, 3, 31), date(2024, 4, 1), BroadcastKind::Quarter)
+                .unwrap();
+        assert_eq!(quarters.len(), 2);
+        let generic: Vec<_> =
+            crate::period::between(&quarters[0], date(2024, 3, 31), date(2024, 4, 1))
+                .unwrap()
+                .collect();
+        assert_eq!(generic, quarters);
+        assert!(
+            BroadcastPeriod::between(date(2024, 4, 1), date(2024, 3, 1), BroadcastKind::Week)
+                .unwrap()
+                .is_empty()
+        );
+
+        let first = BroadcastPeriod::year(BroadcastPeriod::MIN_YEAR).unwrap();
+        assert!(first.pred().is_err());
+        let last = BroadcastPeriod::month(BroadcastPeriod::MAX_YEAR, 12).unwrap();
+        assert!(last.succ().is_err());
+        assert_eq!(last.checked_add(i64::MAX), None);
+        assert_eq!(BroadcastPeriod::week(0, 1).unwrap().checked_sub(1), None);
+        // Large steps must not wrap around into a negative offset
+        let june = BroadcastPeriod::month(2024, 6).unwrap();
+        for n in [1 << 31, u32::MAX] {
+            assert!(june.succ_n(n).is_err(), "{}", n);
+            assert!(june.pred_n(n).is_err(), "{}", n);
+            assert!(BroadcastPeriod::week(2024, 1).unwrap().succ_n(n).is_err());
+        }
+        assert!(BroadcastPeriod::year(BroadcastPeriod::MAX_YEAR + 1).is_err());
+    }
+
+    #[test]
+    fn test_text_and_serde() {
+        for text in ["2024BY", "2024BQ4", "2024BM3", "2023BW53"] {
+            let period = BroadcastPeriod::parse(text).unwrap();
+            assert_eq!(period.to_string(), text);
+            let json = serde_json::to_string(&period).unwrap();
+            assert_eq!(
+                serde_json::from_str::<BroadcastPeriod>(&json).unwrap(),
+                period
+            );
+        }
+        for invalid in [
+            "2024Y", "2024BY1", "2024BQ5", "2024BM", "2024BW53", "2024BX1", "B2024M1", "2024BM+1",
+        ] {
+            assert!(BroadcastPeriod::parse(invalid).is_err(), "{}", invalid);
+        }
+        assert_eq!(
+            "week".parse::<BroadcastKind>().unwrap(),
+            BroadcastKind::Week
+        );
+    }
+}
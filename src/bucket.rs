@@ -0,0 +1,467 @@
+//! Batch assignment of dates to [`DatePeriod`] buckets.
+//!
+//! These functions give the same results as calling
+//! [`DatePeriod::from_date_as_month`] (and friends) on every date. They look
+//! months up in precomputed per-year tables and dispatch on the granularity
+//! once per batch; counting goes through a dense table instead of a map and
+//! grouping sorted input only compares ordinals, which makes both much
+//! cheaper than per-date map updates on large inputs (see
+//! `benches/bucketize.rs`).
+//!
+//! ```rust
+//! use range_date::bucket::{bucketize, count_by_period, group_sorted};
+//! use range_date::range_type::{DatePeriod, PeriodKind};
+//! use chrono::NaiveDate;
+//!
+//! let dates = [
+//!     NaiveDate::from_ymd_opt(2024, 1, 5).unwrap(),
+//!     NaiveDate::from_ymd_opt(2024, 1, 20).unwrap(),
+//!     NaiveDate::from_ymd_opt(2024, 4, 2).unwrap(),
+//! ];
+//!
+//! let months = bucketize(&dates, PeriodKind::Month).unwrap();
+//! assert_eq!(months[2], DatePeriod::month(2024, 4).unwrap());
+//!
+//! let counts = count_by_period(&dates, PeriodKind::Quarter).unwrap();
+//! assert_eq!(counts[&DatePeriod::quarter(2024, 1).unwrap()], 2);
+//!
+//! let groups: Vec<_> = group_sorted(&dates, PeriodKind::Month)
+//!     .map(|group| group.map(|(period, dates)| (period.to_string(), dates.len())))
+//!     .collect::<Result<Vec<_>, _>>()
+//!     .unwrap();
+//! assert_eq!(groups, vec![("2024M1".to_string(), 2), ("2024M4".to_string(), 1)]);
+//! ```
+
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::range_type::{DatePeriod, PeriodKind};
+
+/// Days per month for common (`[0]`) and leap (`[1]`) years.
+const DAYS_IN_MONTH: [[u32; 12]; 2] = [
+    [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+    [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+];
+
+/// `MONTH_END[leap][m]` is the ordinal of the last day of month `m`
+/// (`MONTH_END[leap][0]` is `0`, the day before January 1st).
+static MONTH_END: [[u32; 13]; 2] = build_month_end();
+
+/// `MONTH_OF_ORDINAL[leap][ordinal]` is the month (1-12) containing day
+/// `ordinal` of the year (index `0` is unused).
+static MONTH_OF_ORDINAL: [[u8; 367]; 2] = build_month_of_ordinal();
+
+const fn build_month_end() -> [[u32; 13]; 2] {
+    let mut table = [[0; 13]; 2];
+    let mut leap = 0;
+    while leap < 2 {
+        let mut month = 0;
+        while month < 12 {
+            table[leap][month + 1] = table[leap][month] + DAYS_IN_MONTH[leap][month];
+            month += 1;
+        }
+        leap += 1;
+    }
+    table
+}
+
+const fn build_month_of_ordinal() -> [[u8; 367]; 2] {
+    let month_end = build_month_end();
+    let mut table = [[0; 367]; 2];
+    let mut leap = 0;
+    while leap < 2 {
+        let mut month = 1;
+        let mut ordinal = 1;
+        while ordinal <= month_end[leap][12] as usize {
+            if ordinal as u32 > month_end[leap][month] {
+                month += 1;
+            }
+            table[leap][ordinal] = month as u8;
+            ordinal += 1;
+        }
+        leap += 1;
+    }
+    table
+}
+
+/// Index of the period of `kind` containing `date`, counted in periods of
+/// that kind from year 0.
+fn index_of(date: NaiveDate, kind: PeriodKind) -> i64 {
+    let year = date.year() as i64;
+    match kind {
+        PeriodKind::Year => year,
+        PeriodKind::Quarter => year * 4 + (month_of(date) as i64 - 1) / 3,
+        PeriodKind::Month => year * 12 + month_of(date) as i64 - 1,
+        PeriodKind::Daily => date.num_days_from_ce() as i64,
+    }
+}
+
+/// Inverse of [`index_of`].
+fn period_at(index: i64, kind: PeriodKind) -> Option<DatePeriod> {
+    Some(match kind {
+        PeriodKind::Year => DatePeriod::Year(u32::try_from(index).ok()?),
+        PeriodKind::Quarter => DatePeriod::Quarter(
+            u32::try_from(index.div_euclid(4)).ok()?,
+            index.rem_euclid(4) as u32 + 1,
+        ),
+        PeriodKind::Month => DatePeriod::Month(
+            u32::try_from(index.div_euclid(12)).ok()?,
+            index.rem_euclid(12) as u32 + 1,
+        ),
+        PeriodKind::Daily => period_of(
+            NaiveDate::from_num_days_from_ce_opt(i32::try_from(index).ok()?)?,
+            kind,
+        )
+        .ok()?,
+    })
+}
+
+/// Month (1-12) of `date`, looked up from its ordinal.
+#[inline]
+fn month_of(date: NaiveDate) -> u32 {
+    MONTH_OF_ORDINAL[date.leap_year() as usize][date.ordinal() as usize] as u32
+}
+
+/// The period of `kind` containing `date`.
+#[inline]
+fn period_of(date: NaiveDate, kind: PeriodKind) -> anyhow::Result<DatePeriod> {
+    let year = year_of(date)?;
+    Ok(match kind {
+        PeriodKind::Year => DatePeriod::Year(year),
+        PeriodKind::Quarter => DatePeriod::Quarter(year, (month_of(date) - 1) / 3 + 1),
+        PeriodKind::Month => DatePeriod::Month(year, month_of(date)),
+        PeriodKind::Daily => DatePeriod::Daily(year, date.ordinal()),
+    })
+}
+
+/// The year of `date`, rejecting dates before [`DatePeriod::MIN_YEAR`].
+#[inline]
+fn year_of(date: NaiveDate) -> anyhow::Result<u32> {
+    u32::try_from(date.year())
+        .map_err(|_| anyhow::anyhow!("Date {} is before year {}", date, DatePeriod::MIN_YEAR))
+}
+
+/// Ordinals (days of the year) covered by the period of `kind` containing
+/// `date`.
+fn ordinal_range(date: NaiveDate, kind: PeriodKind) -> RangeInclusive<u32> {
+    let leap = date.leap_year() as usize;
+    match kind {
+        PeriodKind::Year => 1..=MONTH_END[leap][12],
+        PeriodKind::Quarter => {
+            let last_month = ((month_of(date) as usize - 1) / 3 + 1) * 3;
+            MONTH_END[leap][last_month - 3] + 1..=MONTH_END[leap][last_month]
+        }
+        PeriodKind::Month => {
+            let month = month_of(date) as usize;
+            MONTH_END[leap][month - 1] + 1..=MONTH_END[leap][month]
+        }
+        PeriodKind::Daily => date.ordinal()..=date.ordinal(),
+    }
+}
+
+/// Assign every date to the period of `kind` containing it
+///
+/// The result has one entry per input date, in input order, and is identical
+/// to mapping [`DatePeriod::from_date_as_year`] /
+/// [`DatePeriod::from_date_as_quarter`] / [`DatePeriod::from_date_as_month`] /
+/// [`DatePeriod::from_date_as_daily`] over `dates`.
+///
+/// # Errors
+///
+/// Returns an error if any date lies before [`DatePeriod::MIN_YEAR`].
+///
+/// # Examples
+///
+/// ```
+/// use range_date::bucket::bucketize;
+/// use range_date::range_type::{DatePeriod, PeriodKind};
+/// use chrono::NaiveDate;
+///
+/// let dates = [
+///     NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+/// ];
+/// assert_eq!(
+///     bucketize(&dates, PeriodKind::Quarter).unwrap(),
+///     vec![DatePeriod::quarter(2024, 1).unwrap(), DatePeriod::quarter(2024, 2).unwrap()]
+/// );
+/// ```
+pub fn bucketize(dates: &[NaiveDate], kind: PeriodKind) -> anyhow::Result<Vec<DatePeriod>> {
+    // Dispatch once so every loop is specialised for a single kind.
+    match kind {
+        PeriodKind::Year => bucketize_with(dates, |d| period_of(d, PeriodKind::Year)),
+        PeriodKind::Quarter => bucketize_with(dates, |d| period_of(d, PeriodKind::Quarter)),
+        PeriodKind::Month => bucketize_with(dates, |d| period_of(d, PeriodKind::Month)),
+        PeriodKind::Daily => bucketize_with(dates, |d| period_of(d, PeriodKind::Daily)),
+    }
+}
+
+#[inline]
+fn bucketize_with(
+    dates: &[NaiveDate],
+    f: impl Fn(NaiveDate) -> anyhow::Result<DatePeriod>,
+) -> anyhow::Result<Vec<DatePeriod>> {
+    dates.iter().map(|date| f(*date)).collect()
+}
+
+/// Count how many dates fall into each period of `kind`
+///
+/// Periods without any date are not present in the map. Counting happens in
+/// a dense table indexed by period, so the cost does not depend on the input
+/// order; the map is only built once at the end.
+///
+/// # Errors
+///
+/// Returns an error if any date lies before [`DatePeriod::MIN_YEAR`].
+///
+/// # Examples
+///
+/// ```
+/// use range_date::bucket::count_by_period;
+/// use range_date::range_type::{DatePeriod, PeriodKind};
+/// use chrono::NaiveDate;
+///
+/// let dates = [
+///     NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+///     NaiveDate::from_ymd_opt(2023, 5, 1).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 5, 31).unwrap(),
+/// ];
+/// let counts = count_by_period(&dates, PeriodKind::Month).unwrap();
+/// assert_eq!(counts.len(), 2);
+/// assert_eq!(counts[&DatePeriod::month(2024, 5).unwrap()], 2);
+/// ```
+pub fn count_by_period(
+    dates: &[NaiveDate],
+    kind: PeriodKind,
+) -> anyhow::Result<BTreeMap<DatePeriod, usize>> {
+    let (Some(min), Some(max)) = (dates.iter().min(), dates.iter().max()) else {
+        return Ok(BTreeMap::new());
+    };
+    year_of(*min)?;
+    let base = index_of(*min, kind);
+    let span = (index_of(*max, kind) - base + 1) as usize;
+
+    // Very sparse inputs (a few dates centuries apart) would make the dense
+    // table larger than the input itself; count runs in the map instead.
+    if span > dates.len().max(MIN_DENSE_SPAN) {
+        let mut counts = BTreeMap::new();
+        for group in group_sorted(dates, kind) {
+            let (period, group) = group?;
+            *counts.entry(period).or_insert(0) += group.len();
+        }
+        return Ok(counts);
+    }
+
+    let mut table = vec![0usize; span];
+    for date in dates {
+        table[(index_of(*date, kind) - base) as usize] += 1;
+    }
+    Ok(table
+        .into_iter()
+        .enumerate()
+        .filter(|(_, count)| *count > 0)
+        .filter_map(|(offset, count)| Some((period_at(base + offset as i64, kind)?, count)))
+        .collect())
+}
+
+/// Smallest dense table [`count_by_period`] always allows.
+const MIN_DENSE_SPAN: usize = 4096;
+
+/// Group ascending `dates` into consecutive runs sharing a period of `kind`
+///
+/// Each item is a period and the sub-slice of `dates` that falls into it.
+/// Dates are only compared against the bounds of the current run and nothing
+/// is allocated. If `dates` is not sorted the same period may be reported
+/// more than once. A run of dates before [`DatePeriod::MIN_YEAR`] is reported
+/// as an error item.
+///
+/// # Examples
+///
+/// ```
+/// use range_date::bucket::group_sorted;
+/// use range_date::range_type::{DatePeriod, PeriodKind};
+/// use chrono::NaiveDate;
+///
+/// let dates = [
+///     NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 6, 30).unwrap(),
+/// ];
+/// let mut groups = group_sorted(&dates, PeriodKind::Year);
+/// let (period, group) = groups.next().unwrap().unwrap();
+/// assert_eq!((period, group), (DatePeriod::year(2023).unwrap(), &dates[..1]));
+/// let (period, group) = groups.next().unwrap().unwrap();
+/// assert_eq!((period, group), (DatePeriod::year(2024).unwrap(), &dates[1..]));
+/// assert!(groups.next().is_none());
+/// ```
+pub fn group_sorted(dates: &[NaiveDate], kind: PeriodKind) -> SortedGroups<'_> {
+    SortedGroups { rest: dates, kind }
+}
+
+/// Iterator returned by [`group_sorted`]
+#[derive(Debug, Clone)]
+pub struct SortedGroups<'a> {
+    rest: &'a [NaiveDate],
+    kind: PeriodKind,
+}
+
+impl<'a> Iterator for SortedGroups<'a> {
+    type Item = anyhow::Result<(DatePeriod, &'a [NaiveDate])>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = *self.rest.first()?;
+        let year = first.year();
+        let days = ordinal_range(first, self.kind);
+        let len = self
+            .rest
+            .iter()
+            .position(|date| date.year() != year || !days.contains(&date.ordinal()))
+            .unwrap_or(self.rest.len());
+        let (group, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        Some(period_of(first, self.kind).map(|period| (period, group)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo-random dates between 1999 and 2026.
+    fn sample_dates(count: usize) -> Vec<NaiveDate> {
+        let base = NaiveDate::from_ymd_opt(1999, 1, 1).unwrap();
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        (0..count)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                base + chrono::Duration::days(((state >> 33) % 10_000) as i64)
+            })
+            .collect()
+    }
+
+    fn from_date(date: NaiveDate, kind: PeriodKind) -> DatePeriod {
+        DatePeriod::from_date(date, kind).unwrap()
+    }
+
+    const KINDS: [PeriodKind; 4] = PeriodKind::ALL;
+
+    #[test]
+    fn test_month_tables() {
+        assert_eq!(MONTH_END[0][12], 365);
+        assert_eq!(MONTH_END[1][12], 366);
+        assert_eq!(MONTH_OF_ORDINAL[0][59], 2);
+        assert_eq!(MONTH_OF_ORDINAL[0][60], 3);
+        assert_eq!(MONTH_OF_ORDINAL[1][60], 2);
+        assert_eq!(MONTH_OF_ORDINAL[1][366], 12);
+    }
+
+    #[test]
+    fn test_bucketize_matches_from_date() {
+        let dates = sample_dates(5_000);
+        for kind in KINDS {
+            let expected: Vec<_> = dates.iter().map(|d| from_date(*d, kind)).collect();
+            assert_eq!(bucketize(&dates, kind).unwrap(), expected);
+        }
+        assert!(bucketize(&[], PeriodKind::Month).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_count_by_period() {
+        let dates = sample_dates(5_000);
+        for kind in KINDS {
+            let mut expected = BTreeMap::new();
+            for date in &dates {
+                *expected.entry(from_date(*date, kind)).or_insert(0) += 1;
+            }
+            assert_eq!(count_by_period(&dates, kind).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_count_sparse_dates() {
+        let dates = [
+            NaiveDate::from_ymd_opt(1, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(9999, 12, 31).unwrap(),
+            NaiveDate::from_ymd_opt(1, 1, 1).unwrap(),
+        ];
+        let counts = count_by_period(&dates, PeriodKind::Daily).unwrap();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&DatePeriod::Daily(1, 1)], 2);
+        assert_eq!(counts[&DatePeriod::Daily(9999, 365)], 1);
+        assert!(count_by_period(&[], PeriodKind::Year).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_dates_before_year_zero() {
+        let dates = [
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(-1, 12, 31).unwrap(),
+        ];
+        for kind in KINDS {
+            assert!(bucketize(&dates, kind).is_err());
+            assert!(count_by_period(&dates, kind).is_err());
+        }
+        let mut groups = group_sorted(&dates, PeriodKind::Month);
+        assert!(groups.next().unwrap().is_ok());
+        assert!(groups.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_index_round_trip() {
+        for date in sample_dates(1_000) {
+            for kind in KINDS {
+                assert_eq!(
+                    period_at(index_of(date, kind), kind),
+                    Some(from_date(date, kind))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_group_sorted() {
+        let mut dates = sample_dates(5_000);
+        dates.sort();
+        for kind in KINDS {
+            let groups: Vec<_> = group_sorted(&dates, kind)
+                .collect::<anyhow::Result<_>>()
+                .unwrap();
+            assert_eq!(
+                groups.iter().map(|(_, g)| g.len()).sum::<usize>(),
+                dates.len()
+            );
+            for window in groups.windows(2) {
+                assert!(window[0].0 < window[1].0);
+            }
+            for (period, group) in &groups {
+                assert!(group.iter().all(|d| from_date(*d, kind) == *period));
+            }
+        }
+        assert!(group_sorted(&[], PeriodKind::Year).next().is_none());
+    }
+
+    #[test]
+    fn test_group_unsorted_input() {
+        let dates = [
+            NaiveDate::from_ymd_opt(2024, 2, 10).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 11).unwrap(),
+        ];
+        let groups: Vec<_> = group_sorted(&dates, PeriodKind::Month)
+            .map(|group| group.map(|(p, g)| (p, g.len())))
+            .collect::<anyhow::Result<_>>()
+            .unwrap();
+        assert_eq!(
+            groups,
+            vec![
+                (DatePeriod::Month(2024, 2), 1),
+                (DatePeriod::Month(2024, 1), 1),
+                (DatePeriod::Month(2024, 2), 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,352 @@
+//! Fixed-length cycles anchored to a reference date.
+//!
+//! Billing cycles ("every 28 days from 2023-01-05") and sprints don't line up
+//! with calendar months. A [`Cycle`] is one such period: cycle `0` starts on
+//! the anchor date, cycle `n` starts `n * length_days` days later, and
+//! negative indexes count backwards from the anchor.
+//!
+//! ```rust
+//! use range_date::cycle::Cycle;
+//! use range_date::period::Period;
+//! use chrono::NaiveDate;
+//!
+//! let anchor = NaiveDate::from_ymd_opt(2023, 1, 5).unwrap();
+//! let date = NaiveDate::from_ymd_opt(2023, 3, 15).unwrap();
+//!
+//! let cycle = Cycle::from_date(anchor, 28, date).unwrap();
+//! assert_eq!(cycle.index(), 2);
+//! assert_eq!(cycle.get_first_day(), NaiveDate::from_ymd_opt(2023, 3, 2).unwrap());
+//! assert_eq!(cycle.get_last_day(), NaiveDate::from_ymd_opt(2023, 3, 29).unwrap());
+//! assert_eq!(cycle.to_string(), "28D@2023-01-05#2");
+//! assert_eq!(cycle.succ().unwrap().index(), 3);
+//! ```
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::period::Period;
+use crate::typed::Day;
+
+/// One cycle of `length_days` days, counted from `anchor`
+///
+/// The textual form is `<length>D@<anchor>#<index>`, e.g.
+/// `28D@2023-01-05#2` for the third 28-day cycle starting on 2023-01-05.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cycle {
+    anchor: NaiveDate,
+    length_days: u32,
+    index: i64,
+}
+
+impl Cycle {
+    /// Create cycle number `index` of the cycles of `length_days` days
+    /// starting on `anchor`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `length_days` is zero or the cycle does not lie
+    /// within the dates [`NaiveDate`] can represent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::cycle::Cycle;
+    /// use chrono::NaiveDate;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let sprint = Cycle::new(anchor, 14, -1).unwrap();
+    /// assert_eq!(sprint.get_first_day(), NaiveDate::from_ymd_opt(2023, 12, 18).unwrap());
+    /// assert!(Cycle::new(anchor, 0, 0).is_err());
+    /// ```
+    pub fn new(anchor: NaiveDate, length_days: u32, index: i64) -> anyhow::Result<Self> {
+        if length_days == 0 {
+            return Err(anyhow::anyhow!("Cycle length must be at least 1 day"));
+        }
+        let cycle = Cycle {
+            anchor,
+            length_days,
+            index,
+        };
+        cycle.bounds().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Cycle {} of {} days from {} is out of range",
+                index,
+                length_days,
+                anchor
+            )
+        })?;
+        Ok(cycle)
+    }
+
+    /// Create the cycle of `length_days` days from `anchor` that contains
+    /// `date`
+    ///
+    /// Dates before the anchor fall into cycles with negative indexes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `length_days` is zero or the cycle containing
+    /// `date` does not lie within the dates [`NaiveDate`] can represent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::cycle::Cycle;
+    /// use chrono::NaiveDate;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2023, 1, 5).unwrap();
+    /// let before = NaiveDate::from_ymd_opt(2023, 1, 4).unwrap();
+    /// assert_eq!(Cycle::from_date(anchor, 28, before).unwrap().index(), -1);
+    /// ```
+    pub fn from_date(anchor: NaiveDate, length_days: u32, date: NaiveDate) -> anyhow::Result<Self> {
+        if length_days == 0 {
+            return Err(anyhow::anyhow!("Cycle length must be at least 1 day"));
+        }
+        let offset = (date - anchor).num_days();
+        Self::new(anchor, length_days, offset.div_euclid(length_days as i64))
+    }
+
+    /// Generate all cycles between two dates (inclusive)
+    /// Returns an empty vector if start > end
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `length_days` is zero or a cycle lies outside the
+    /// dates [`NaiveDate`] can represent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::cycle::Cycle;
+    /// use chrono::NaiveDate;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+    /// let sprints = Cycle::between(anchor, 14, start, end).unwrap();
+    /// assert_eq!(sprints.len(), 3);
+    /// assert_eq!(sprints[0].index(), 0);
+    /// ```
+    pub fn between(
+        anchor: NaiveDate,
+        length_days: u32,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> anyhow::Result<Vec<Cycle>> {
+        if start > end {
+            return Ok(vec![]);
+        }
+        let first = Self::from_date(anchor, length_days, start)?;
+        let last = Self::from_date(anchor, length_days, end)?;
+        Ok((first.index..=last.index)
+            .map(|index| Cycle { index, ..first })
+            .collect())
+    }
+
+    /// Get the anchor date, the first day of cycle `0`
+    pub fn anchor(&self) -> NaiveDate {
+        self.anchor
+    }
+
+    /// Get the length of every cycle in days
+    pub fn length_days(&self) -> u32 {
+        self.length_days
+    }
+
+    /// Get the number of cycles between the anchor and this cycle
+    pub fn index(&self) -> i64 {
+        self.index
+    }
+
+    /// Get the first day of the cycle
+    pub fn get_first_day(&self) -> NaiveDate {
+        self.bounds()
+            .map_or(self.anchor, |(first_day, _)| first_day)
+    }
+
+    /// Get the last day of the cycle (inclusive)
+    pub fn get_last_day(&self) -> NaiveDate {
+        self.bounds().map_or(self.anchor, |(_, last_day)| last_day)
+    }
+
+    /// Check if `date` lies within the cycle
+    pub fn contains_date(&self, date: NaiveDate) -> bool {
+        self.get_first_day() <= date && date <= self.get_last_day()
+    }
+
+    /// First and last day, `None` if either is not representable
+    fn bounds(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let length = self.length_days as i64;
+        let first_day = self
+            .anchor
+            .checked_add_signed(Duration::try_days(self.index.checked_mul(length)?)?)?;
+        let last_day = first_day.checked_add_signed(Duration::try_days(length - 1)?)?;
+        Some((first_day, last_day))
+    }
+}
+
+impl std::fmt::Display for Cycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}D@{}#{}",
+            self.length_days,
+            self.anchor.format("%Y-%m-%d"),
+            self.index
+        )
+    }
+}
+
+impl std::str::FromStr for Cycle {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || anyhow::anyhow!("Invalid cycle format: {}", s);
+        let (length, rest) = s.split_once("D@").ok_or_else(invalid)?;
+        let (anchor, index) = rest.split_once('#').ok_or_else(invalid)?;
+        let length_days = length.parse::<u32>().map_err(|_| invalid())?;
+        let anchor = NaiveDate::parse_from_str(anchor, "%Y-%m-%d").map_err(|_| invalid())?;
+        let index = index.parse::<i64>().map_err(|_| invalid())?;
+        Self::new(anchor, length_days, index)
+    }
+}
+
+impl Serialize for Cycle {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Cycle {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Cycle, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use std::str::FromStr;
+        let s = String::deserialize(deserializer)?;
+        Cycle::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Period for Cycle {
+    type Parent = Cycle;
+    type Child = Day;
+
+    fn get_first_day(&self) -> anyhow::Result<NaiveDate> {
+        Ok(Cycle::get_first_day(self))
+    }
+
+    fn get_last_day(&self) -> anyhow::Result<NaiveDate> {
+        Ok(Cycle::get_last_day(self))
+    }
+
+    fn checked_add(&self, n: i64) -> Option<Self> {
+        let cycle = Cycle {
+            index: self.index.checked_add(n)?,
+            ..*self
+        };
+        cycle.bounds().map(|_| cycle)
+    }
+
+    fn containing(&self, date: NaiveDate) -> anyhow::Result<Self> {
+        Self::from_date(self.anchor, self.length_days, date)
+    }
+
+    fn children(&self) -> Vec<Day> {
+        self.get_first_day()
+            .iter_days()
+            .take(self.length_days as usize)
+            .filter_map(|date| Day::from_date(date).ok())
+            .collect()
+    }
+
+    fn contains_date(&self, date: NaiveDate) -> bool {
+        Cycle::contains_date(self, date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::period;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_cycles_tile_the_calendar() {
+        let anchor = date(2023, 1, 5);
+        let mut day = date(2022, 6, 1);
+        let mut previous: Option<Cycle> = None;
+        while day < date(2024, 6, 1) {
+            let cycle = Cycle::from_date(anchor, 28, day).unwrap();
+            assert!(cycle.contains_date(day));
+            assert_eq!(
+                Cycle::get_last_day(&cycle) - Cycle::get_first_day(&cycle),
+                Duration::days(27)
+            );
+            if let Some(previous) = previous.filter(|p| *p != cycle) {
+                assert_eq!(previous.succ().unwrap(), cycle);
+                assert_eq!(Cycle::get_first_day(&cycle), day);
+            }
+            previous = Some(cycle);
+            day = day.succ_opt().unwrap();
+        }
+        assert_eq!(Cycle::from_date(anchor, 28, anchor).unwrap().index(), 0);
+        assert_eq!(
+            Cycle::from_date(anchor, 28, date(2023, 1, 4))
+                .unwrap()
+                .index(),
+            -1
+        );
+    }
+
+    #[test]
+    fn test_between_and_generic_iteration() {
+        let anchor = date(2024, 1, 1);
+        let start = date(2023, 12, 25);
+        let end = date(2024, 2, 1);
+        let sprints = Cycle::between(anchor, 14, start, end).unwrap();
+        assert_eq!(
+            sprints.iter().map(Cycle::index).collect::<Vec<_>>(),
+            vec![-1, 0, 1, 2]
+        );
+        let generic: Vec<_> = period::between(&sprints[0], start, end).unwrap().collect();
+        assert_eq!(generic, sprints);
+        assert!(Cycle::between(anchor, 14, end, start).unwrap().is_empty());
+        assert_eq!(sprints[1].children().len(), 14);
+        assert_eq!(sprints[1].offset_n(-1).unwrap(), sprints[0]);
+    }
+
+    #[test]
+    fn test_text_and_serde() {
+        let cycle = Cycle::new(date(2023, 1, 5), 28, -3).unwrap();
+        assert_eq!(cycle.to_string(), "28D@2023-01-05#-3");
+        assert_eq!("28D@2023-01-05#-3".parse::<Cycle>().unwrap(), cycle);
+        let json = serde_json::to_string(&cycle).unwrap();
+        assert_eq!(serde_json::from_str::<Cycle>(&json).unwrap(), cycle);
+
+        for invalid in [
+            "28D2023-01-05#1",
+            "0D@2023-01-05#1",
+            "28D@2023-13-05#1",
+            "28D@2023-01-05#x",
+        ] {
+            assert!(invalid.parse::<Cycle>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert!(Cycle::new(NaiveDate::MAX, 2, 0).is_err());
+        assert!(Cycle::new(date(2024, 1, 1), 7, i64::MAX).is_err());
+        let last = Cycle::new(NaiveDate::MAX - Duration::days(6), 7, 0).unwrap();
+        assert!(last.succ().is_err());
+        assert_eq!(last.checked_add(i64::MIN), None);
+        assert!(Cycle::from_date(date(2024, 1, 1), 0, date(2024, 1, 1)).is_err());
+    }
+}
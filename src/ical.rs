@@ -0,0 +1,317 @@
+//! iCalendar (RFC 5545) export for [`DatePeriod`] values.
+//!
+//! Every period becomes one all-day `VEVENT`: `DTSTART` is the period's
+//! [`DatePeriod::get_first_day`] and `DTEND` is the day after
+//! [`DatePeriod::get_last_day`] (the end date is exclusive in iCalendar).
+//!
+//! ```rust
+//! use range_date::ical::IcsExporter;
+//! use range_date::range_type::DatePeriod;
+//! use chrono::NaiveDate;
+//!
+//! let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+//! let end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+//! let quarters = DatePeriod::between_date_as_quarter(start, end).unwrap();
+//!
+//! let ics = IcsExporter::new()
+//!     .with_summary(|p: &DatePeriod| format!("Quarter close {}", p))
+//!     .to_ics(&quarters)
+//!     .unwrap();
+//! assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+//! assert!(ics.contains("DTSTART;VALUE=DATE:20240101\r\n"));
+//! assert!(ics.contains("DTEND;VALUE=DATE:20240401\r\n"));
+//! assert!(ics.contains("SUMMARY:Quarter close 2024Q1\r\n"));
+//! ```
+
+use std::io::Write;
+
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+
+use crate::range_type::DatePeriod;
+
+/// Maximum length of a content line in octets, excluding the line break.
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Summary callback used when none is configured: the period's string form.
+fn default_summary(period: &DatePeriod) -> String {
+    period.to_string()
+}
+
+/// Renders [`DatePeriod`]s as an iCalendar document of all-day events
+///
+/// The exporter is configured through its `with_*` methods and then used with
+/// [`IcsExporter::to_ics`] or [`IcsExporter::write_ics`].
+#[derive(Debug, Clone)]
+pub struct IcsExporter<F = fn(&DatePeriod) -> String> {
+    summary: F,
+    prod_id: String,
+    uid_domain: String,
+    dtstamp: Option<NaiveDateTime>,
+}
+
+impl IcsExporter {
+    /// Create an exporter that uses the period string (e.g. `"2024Q1"`) as
+    /// the event summary
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::ical::IcsExporter;
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let ics = IcsExporter::new()
+    ///     .to_ics(&[DatePeriod::month(2024, 2).unwrap()])
+    ///     .unwrap();
+    /// assert!(ics.contains("SUMMARY:2024M2\r\n"));
+    /// assert!(ics.contains("DTEND;VALUE=DATE:20240301\r\n"));
+    /// ```
+    pub fn new() -> Self {
+        IcsExporter {
+            summary: default_summary,
+            prod_id: "-//range_date//range_date//EN".to_string(),
+            uid_domain: "range_date".to_string(),
+            dtstamp: None,
+        }
+    }
+}
+
+impl Default for IcsExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F> IcsExporter<F>
+where
+    F: Fn(&DatePeriod) -> String,
+{
+    /// Use `summary` to build the `SUMMARY` of each event
+    pub fn with_summary<G>(self, summary: G) -> IcsExporter<G>
+    where
+        G: Fn(&DatePeriod) -> String,
+    {
+        IcsExporter {
+            summary,
+            prod_id: self.prod_id,
+            uid_domain: self.uid_domain,
+            dtstamp: self.dtstamp,
+        }
+    }
+
+    /// Set the calendar `PRODID`
+    pub fn with_prod_id(mut self, prod_id: impl Into<String>) -> Self {
+        self.prod_id = prod_id.into();
+        self
+    }
+
+    /// Set the domain part of each event `UID` (`<period>@<domain>`)
+    ///
+    /// UIDs are derived from the period only, so re-exporting the same
+    /// periods updates existing events instead of duplicating them.
+    pub fn with_uid_domain(mut self, uid_domain: impl Into<String>) -> Self {
+        self.uid_domain = uid_domain.into();
+        self
+    }
+
+    /// Set the UTC `DTSTAMP` written on every event
+    ///
+    /// Defaults to the current time at export.
+    pub fn with_dtstamp(mut self, dtstamp: NaiveDateTime) -> Self {
+        self.dtstamp = Some(dtstamp);
+        self
+    }
+
+    /// Render `periods` as an iCalendar document
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the boundaries of a period cannot be computed
+    /// (see [`DatePeriod::get_first_day`] / [`DatePeriod::get_last_day`]).
+    pub fn to_ics(&self, periods: &[DatePeriod]) -> anyhow::Result<String> {
+        let mut buf = Vec::new();
+        self.write_ics(periods, &mut buf)?;
+        Ok(String::from_utf8(buf)?)
+    }
+
+    /// Write `periods` as an iCalendar document to `writer`
+    ///
+    /// Lines are terminated with CRLF and folded at 75 octets as required by
+    /// RFC 5545.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the boundaries of a period cannot be computed or if
+    /// writing to `writer` fails.
+    pub fn write_ics<W: Write>(&self, periods: &[DatePeriod], mut writer: W) -> anyhow::Result<()> {
+        let dtstamp = self
+            .dtstamp
+            .unwrap_or_else(|| Utc::now().naive_utc())
+            .format("%Y%m%dT%H%M%SZ")
+            .to_string();
+
+        write_line(&mut writer, "BEGIN:VCALENDAR")?;
+        write_line(&mut writer, "VERSION:2.0")?;
+        write_line(
+            &mut writer,
+            &format!("PRODID:{}", escape_text(&self.prod_id)),
+        )?;
+        write_line(&mut writer, "CALSCALE:GREGORIAN")?;
+        for period in periods {
+            let first_day = period.get_first_day()?;
+            let end_day = period
+                .get_last_day()?
+                .succ_opt()
+                .ok_or_else(|| anyhow::anyhow!("No day after the end of {}", period))?;
+
+            write_line(&mut writer, "BEGIN:VEVENT")?;
+            write_line(
+                &mut writer,
+                &format!("UID:{}@{}", period, escape_text(&self.uid_domain)),
+            )?;
+            write_line(&mut writer, &format!("DTSTAMP:{}", dtstamp))?;
+            write_line(
+                &mut writer,
+                &format!("DTSTART;VALUE=DATE:{}", format_date(first_day)),
+            )?;
+            write_line(
+                &mut writer,
+                &format!("DTEND;VALUE=DATE:{}", format_date(end_day)),
+            )?;
+            write_line(
+                &mut writer,
+                &format!("SUMMARY:{}", escape_text(&(self.summary)(period))),
+            )?;
+            write_line(&mut writer, "TRANSP:TRANSPARENT")?;
+            write_line(&mut writer, "END:VEVENT")?;
+        }
+        write_line(&mut writer, "END:VCALENDAR")?;
+        Ok(())
+    }
+}
+
+/// Format a date as an iCalendar `DATE` value (`YYYYMMDD`)
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+/// Escape a `TEXT` value (RFC 5545 section 3.3.11)
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Write one content line, folding it so that no physical line exceeds
+/// [`MAX_LINE_OCTETS`] and never splitting a UTF-8 sequence
+fn write_line<W: Write>(writer: &mut W, line: &str) -> std::io::Result<()> {
+    let mut limit = MAX_LINE_OCTETS;
+    let mut rest = line;
+    while rest.len() > limit {
+        let mut split = limit;
+        while !rest.is_char_boundary(split) {
+            split -= 1;
+        }
+        writer.write_all(&rest.as_bytes()[..split])?;
+        writer.write_all(b"\r\n ")?;
+        rest = &rest[split..];
+        // Continuation lines start with a space, which counts towards the limit.
+        limit = MAX_LINE_OCTETS - 1;
+    }
+    writer.write_all(rest.as_bytes())?;
+    writer.write_all(b"\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn fixed_stamp() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 1, 2)
+            .unwrap()
+            .and_hms_opt(3, 4, 5)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_to_ics_document() {
+        let ics = IcsExporter::new()
+            .with_dtstamp(fixed_stamp())
+            .to_ics(&[DatePeriod::quarter(2024, 4).unwrap()])
+            .unwrap();
+        assert_eq!(
+            ics,
+            "BEGIN:VCALENDAR\r\n\
+             VERSION:2.0\r\n\
+             PRODID:-//range_date//range_date//EN\r\n\
+             CALSCALE:GREGORIAN\r\n\
+             BEGIN:VEVENT\r\n\
+             UID:2024Q4@range_date\r\n\
+             DTSTAMP:20240102T030405Z\r\n\
+             DTSTART;VALUE=DATE:20241001\r\n\
+             DTEND;VALUE=DATE:20250101\r\n\
+             SUMMARY:2024Q4\r\n\
+             TRANSP:TRANSPARENT\r\n\
+             END:VEVENT\r\n\
+             END:VCALENDAR\r\n"
+        );
+    }
+
+    #[test]
+    fn test_daily_and_between_periods() {
+        let start = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let days = DatePeriod::between_date_as_daily(start, end).unwrap();
+        let ics = IcsExporter::new()
+            .with_dtstamp(fixed_stamp())
+            .to_ics(&days)
+            .unwrap();
+
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 3);
+        assert!(ics.contains("DTSTART;VALUE=DATE:20240229\r\nDTEND;VALUE=DATE:20240301\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20240301\r\nDTEND;VALUE=DATE:20240302\r\n"));
+    }
+
+    #[test]
+    fn test_summary_escaping_and_folding() {
+        let summary = "Close; review, sign-off\\approve\n".to_string() + &"é".repeat(60);
+        let ics = IcsExporter::new()
+            .with_dtstamp(fixed_stamp())
+            .with_summary(move |_: &DatePeriod| summary.clone())
+            .to_ics(&[DatePeriod::year(2024).unwrap()])
+            .unwrap();
+
+        for line in ics.split("\r\n") {
+            assert!(line.len() <= MAX_LINE_OCTETS, "line too long: {line:?}");
+        }
+        let unfolded = ics.replace("\r\n ", "");
+        assert!(unfolded.contains(&format!(
+            "SUMMARY:Close\\; review\\, sign-off\\\\approve\\n{}\r\n",
+            "é".repeat(60)
+        )));
+    }
+
+    #[test]
+    fn test_write_ics_to_writer() {
+        let mut out = Vec::new();
+        IcsExporter::new()
+            .with_prod_id("-//Example//Reporting//EN")
+            .with_uid_domain("example.com")
+            .write_ics(&[DatePeriod::month(2024, 12).unwrap()], &mut out)
+            .unwrap();
+        let ics = String::from_utf8(out).unwrap();
+        assert!(ics.contains("PRODID:-//Example//Reporting//EN\r\n"));
+        assert!(ics.contains("UID:2024M12@example.com\r\n"));
+        assert!(ics.contains("DTEND;VALUE=DATE:20250101\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    }
+}
@@ -0,0 +1,472 @@
+//! Intraday periods: hours and fixed-length minute slots below
+//! [`DatePeriod::Daily`].
+//!
+//! A [`TimeSlot`] is one of the equal slots a day is cut into, e.g. the 14th
+//! hour or the 53rd quarter hour. Slot lengths must divide a day evenly, so
+//! slots never cross midnight and each one belongs to exactly one daily
+//! period ([`TimeSlot::aggregate`]). Navigation continues across day and year
+//! boundaries.
+//!
+//! ```rust
+//! use range_date::intraday::TimeSlot;
+//! use range_date::range_type::DatePeriod;
+//! use chrono::NaiveDate;
+//!
+//! let dt = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().and_hms_opt(13, 20, 0).unwrap();
+//! let hour = TimeSlot::hour_of(dt).unwrap();
+//! assert_eq!(hour.to_string(), "2024D60H13");
+//! assert_eq!(hour.aggregate(), DatePeriod::daily(2024, 60).unwrap());
+//!
+//! let quarter_hour = TimeSlot::from_datetime(dt, 15).unwrap();
+//! assert_eq!(quarter_hour.to_string(), "2024D60H13M15/15");
+//! assert_eq!(quarter_hour.start().time().to_string(), "13:15:00");
+//! assert_eq!(quarter_hour.end().unwrap().time().to_string(), "13:30:00");
+//! ```
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::period::Period;
+use crate::range_type::{DatePeriod, PeriodKind, local_start};
+
+/// Number of minutes in a day.
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// One of the equal-length slots of a day
+///
+/// The textual form is the daily period followed by the slot start:
+/// `2024D60H13` for an hour, `2024D60H13M15/15` for a slot of another length
+/// (here the 15-minute slot starting at 13:15).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeSlot {
+    day: DatePeriod,
+    length_minutes: u32,
+    index: u32,
+}
+
+impl TimeSlot {
+    /// Create slot number `index` (from 0) of the `length_minutes` slots of
+    /// the daily period `day`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `day` is not a daily period, `length_minutes` does
+    /// not divide a day (1440 minutes) evenly, or `index` is not a slot of
+    /// the day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::intraday::TimeSlot;
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let day = DatePeriod::daily(2024, 1).unwrap();
+    /// let slot = TimeSlot::new(day, 30, 3).unwrap();
+    /// assert_eq!(slot.to_string(), "2024D1H1M30/30");
+    /// assert!(TimeSlot::new(day, 7, 0).is_err());
+    /// assert!(TimeSlot::new(day, 30, 48).is_err());
+    /// ```
+    pub fn new(day: DatePeriod, length_minutes: u32, index: u32) -> anyhow::Result<Self> {
+        if day.kind() != PeriodKind::Daily {
+            return Err(anyhow::anyhow!(
+                "Time slots require a daily period, got: {}",
+                day
+            ));
+        }
+        if length_minutes == 0 || !MINUTES_PER_DAY.is_multiple_of(length_minutes) {
+            return Err(anyhow::anyhow!(
+                "Slot length must divide {} minutes, got: {}",
+                MINUTES_PER_DAY,
+                length_minutes
+            ));
+        }
+        let slots = MINUTES_PER_DAY / length_minutes;
+        if index >= slots {
+            return Err(anyhow::anyhow!(
+                "Slot index must be below {} for {}-minute slots, got: {}",
+                slots,
+                length_minutes,
+                index
+            ));
+        }
+        Ok(TimeSlot {
+            day,
+            length_minutes,
+            index,
+        })
+    }
+
+    /// Create the hour `hour` (0-23) of the daily period `day`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `day` is not a daily period or `hour` is not in
+    /// `0..24`.
+    pub fn hour(day: DatePeriod, hour: u32) -> anyhow::Result<Self> {
+        Self::new(day, 60, hour)
+    }
+
+    /// Create the `length_minutes` slot containing `dt`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `length_minutes` does not divide a day evenly or
+    /// `dt` lies before [`DatePeriod::MIN_YEAR`].
+    pub fn from_datetime(dt: NaiveDateTime, length_minutes: u32) -> anyhow::Result<Self> {
+        let day = DatePeriod::from_date_as_daily(dt.date())?;
+        let minute = dt.hour() * 60 + dt.minute();
+        Self::new(day, length_minutes, minute / length_minutes.max(1))
+    }
+
+    /// Create the hour containing `dt`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dt` lies before [`DatePeriod::MIN_YEAR`].
+    pub fn hour_of(dt: NaiveDateTime) -> anyhow::Result<Self> {
+        Self::from_datetime(dt, 60)
+    }
+
+    /// Get all `length_minutes` slots of the daily period `day`, in order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `day` is not a daily period or `length_minutes`
+    /// does not divide a day evenly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::intraday::TimeSlot;
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let day = DatePeriod::daily(2024, 60).unwrap();
+    /// assert_eq!(TimeSlot::slots_of(day, 60).unwrap().len(), 24);
+    /// assert_eq!(TimeSlot::slots_of(day, 15).unwrap().len(), 96);
+    /// ```
+    pub fn slots_of(day: DatePeriod, length_minutes: u32) -> anyhow::Result<Vec<TimeSlot>> {
+        let first = Self::new(day, length_minutes, 0)?;
+        Ok((0..MINUTES_PER_DAY / length_minutes)
+            .map(|index| TimeSlot { index, ..first })
+            .collect())
+    }
+
+    /// Get the daily period containing this slot
+    pub fn aggregate(&self) -> DatePeriod {
+        self.day
+    }
+
+    /// Get the slot length in minutes
+    pub fn length_minutes(&self) -> u32 {
+        self.length_minutes
+    }
+
+    /// Get the position of the slot within its day, from 0
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Get the local start of the slot
+    pub fn start(&self) -> NaiveDateTime {
+        self.date().and_time(NaiveTime::MIN) + Duration::minutes(self.start_minute() as i64)
+    }
+
+    /// Get the local end of the slot, exclusive
+    ///
+    /// This is the start of the next slot, i.e. midnight of the next day for
+    /// the last slot of a day.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the last slot of [`NaiveDate::MAX`], whose end
+    /// is not representable.
+    pub fn end(&self) -> anyhow::Result<NaiveDateTime> {
+        self.start()
+            .checked_add_signed(Duration::minutes(self.length_minutes as i64))
+            .ok_or_else(|| anyhow::anyhow!("End of time slot {} is out of range", self))
+    }
+
+    /// Check if the local time `dt` lies within `[start, end)`
+    pub fn contains_datetime(&self, dt: NaiveDateTime) -> bool {
+        // A slot without a representable end contains all later times
+        self.start() <= dt && self.end().ok().is_none_or(|end| dt < end)
+    }
+
+    /// Get the first instant of the slot in `tz`
+    ///
+    /// Local times skipped by a DST transition move to the first existing
+    /// minute after the gap; ambiguous local times use the earlier instant.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no local time from the slot start onwards exists
+    /// in `tz` within a day.
+    pub fn start_instant<Tz: TimeZone>(&self, tz: &Tz) -> anyhow::Result<DateTime<Tz>> {
+        local_start(self.start(), tz)
+    }
+
+    /// Get the first instant after the slot in `tz`, so the slot covers the
+    /// half-open range `[start_instant, end_instant)`
+    ///
+    /// # Errors
+    ///
+    /// See [`TimeSlot::start_instant`] and [`TimeSlot::end`].
+    pub fn end_instant<Tz: TimeZone>(&self, tz: &Tz) -> anyhow::Result<DateTime<Tz>> {
+        local_start(self.end()?, tz)
+    }
+
+    /// Minute of the day the slot starts at
+    fn start_minute(&self) -> u32 {
+        self.index * self.length_minutes
+    }
+
+    /// Calendar date of the slot's day
+    fn date(&self) -> NaiveDate {
+        // Slots are only built from valid daily periods
+        self.day.to_date().unwrap_or(NaiveDate::MIN)
+    }
+}
+
+impl std::fmt::Display for TimeSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (hour, minute) = (self.start_minute() / 60, self.start_minute() % 60);
+        if self.length_minutes == 60 {
+            write!(f, "{}H{}", self.day, hour)
+        } else {
+            write!(
+                f,
+                "{}H{}M{}/{}",
+                self.day, hour, minute, self.length_minutes
+            )
+        }
+    }
+}
+
+impl std::str::FromStr for TimeSlot {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || anyhow::anyhow!("Invalid time slot format: {}", s);
+        let s = s.trim();
+        let (day, time) = s.rsplit_once('H').ok_or_else(invalid)?;
+        let day = DatePeriod::parse(day)?;
+        let (hour, minute, length) = match time.split_once('M') {
+            None => (time, "0", "60"),
+            Some((hour, rest)) => {
+                let (minute, length) = rest.split_once('/').ok_or_else(invalid)?;
+                (hour, minute, length)
+            }
+        };
+        let hour: u32 = hour.parse().map_err(|_| invalid())?;
+        let minute: u32 = minute.parse().map_err(|_| invalid())?;
+        let length: u32 = length.parse().map_err(|_| invalid())?;
+        let start = hour
+            .checked_mul(60)
+            .and_then(|minutes| minutes.checked_add(minute))
+            .filter(|_| minute < 60)
+            .ok_or_else(invalid)?;
+        if length == 0 || !start.is_multiple_of(length) {
+            return Err(anyhow::anyhow!(
+                "Slot start {:02}:{:02} is not aligned to {}-minute slots",
+                hour,
+                minute,
+                length
+            ));
+        }
+        Self::new(day, length, start / length)
+    }
+}
+
+impl Serialize for TimeSlot {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeSlot {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<TimeSlot, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use std::str::FromStr;
+        let s = String::deserialize(deserializer)?;
+        TimeSlot::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Period for TimeSlot {
+    type Parent = DatePeriod;
+    type Child = TimeSlot;
+
+    fn get_first_day(&self) -> anyhow::Result<NaiveDate> {
+        self.day.get_first_day()
+    }
+
+    fn get_last_day(&self) -> anyhow::Result<NaiveDate> {
+        self.day.get_last_day()
+    }
+
+    fn checked_add(&self, n: i64) -> Option<Self> {
+        let slots = (MINUTES_PER_DAY / self.length_minutes) as i64;
+        let position = (self.day.ordinal().checked_mul(slots)?)
+            .checked_add(self.index as i64)?
+            .checked_add(n)?;
+        let day = DatePeriod::from_ordinal(PeriodKind::Daily, position.div_euclid(slots)).ok()?;
+        Some(TimeSlot {
+            day,
+            index: position.rem_euclid(slots) as u32,
+            ..*self
+        })
+    }
+
+    fn containing(&self, date: NaiveDate) -> anyhow::Result<Self> {
+        Self::new(
+            DatePeriod::from_date_as_daily(date)?,
+            self.length_minutes,
+            0,
+        )
+    }
+
+    fn containing_last(&self, date: NaiveDate) -> anyhow::Result<Self> {
+        Self::new(
+            DatePeriod::from_date_as_daily(date)?,
+            self.length_minutes,
+            MINUTES_PER_DAY / self.length_minutes - 1,
+        )
+    }
+
+    fn parent(&self) -> Option<DatePeriod> {
+        Some(self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, Utc};
+
+    fn datetime(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_slots_tile_the_day() {
+        let day = DatePeriod::daily(2024, 60).unwrap();
+        for length in [1, 5, 15, 60, 90, 240, 1440] {
+            let slots = TimeSlot::slots_of(day, length).unwrap();
+            assert_eq!(slots.len() as u32 * length, MINUTES_PER_DAY);
+            assert_eq!(slots[0].start(), datetime(2024, 2, 29, 0, 0));
+            assert_eq!(
+                slots.last().unwrap().end().unwrap(),
+                datetime(2024, 3, 1, 0, 0)
+            );
+            for pair in slots.windows(2) {
+                assert_eq!(pair[0].end().unwrap(), pair[1].start());
+                assert_eq!(pair[0].succ().unwrap(), pair[1]);
+            }
+            for slot in &slots {
+                assert_eq!(
+                    TimeSlot::from_datetime(slot.start(), length).unwrap(),
+                    *slot
+                );
+                assert!(slot.contains_datetime(slot.start()));
+                assert!(!slot.contains_datetime(slot.end().unwrap()));
+                assert_eq!(slot.parent(), Some(day));
+            }
+        }
+        assert!(TimeSlot::slots_of(day, 0).is_err());
+        assert!(TimeSlot::slots_of(day, 17).is_err());
+        assert!(TimeSlot::slots_of(DatePeriod::month(2024, 2).unwrap(), 60).is_err());
+    }
+
+    #[test]
+    fn test_generic_between_covers_whole_days() {
+        let day = DatePeriod::daily(2024, 60).unwrap();
+        let date = day.to_date().unwrap();
+        for length in [15, 60, 1440] {
+            let family = TimeSlot::new(DatePeriod::daily(2000, 1).unwrap(), length, 0).unwrap();
+            let slots: Vec<_> = crate::period::between(&family, date, date)
+                .unwrap()
+                .collect();
+            assert_eq!(slots, TimeSlot::slots_of(day, length).unwrap());
+
+            let next = date.succ_opt().unwrap();
+            let two_days = crate::period::between(&family, date, next).unwrap().count();
+            assert_eq!(two_days as u32 * length, 2 * MINUTES_PER_DAY);
+        }
+    }
+
+    #[test]
+    fn test_navigation_across_days_and_years() {
+        let last_hour = TimeSlot::hour_of(datetime(2023, 12, 31, 23, 59)).unwrap();
+        let next = last_hour.succ().unwrap();
+        assert_eq!(next.to_string(), "2024D1H0");
+        assert_eq!(next.pred().unwrap(), last_hour);
+        assert_eq!(
+            last_hour.offset_n(59 * 24 + 1).unwrap().start(),
+            datetime(2024, 2, 29, 0, 0)
+        );
+
+        let slot = TimeSlot::from_datetime(datetime(2024, 1, 1, 0, 10), 15).unwrap();
+        assert_eq!(slot.pred().unwrap().start(), datetime(2023, 12, 31, 23, 45));
+
+        let first = TimeSlot::hour(DatePeriod::daily(0, 1).unwrap(), 0).unwrap();
+        assert!(first.pred().is_err());
+        let last = TimeSlot::hour_of(NaiveDate::MAX.and_hms_opt(23, 0, 0).unwrap()).unwrap();
+        assert!(last.succ().is_err());
+        // The end of the last slot lies past the last representable date
+        assert!(last.end().is_err());
+        assert!(last.end_instant(&Utc).is_err());
+        assert!(last.start_instant(&Utc).is_ok());
+        assert!(last.contains_datetime(NaiveDate::MAX.and_hms_opt(23, 59, 59).unwrap()));
+        assert!(!last.contains_datetime(NaiveDate::MAX.and_hms_opt(22, 59, 59).unwrap()));
+        assert_eq!(first.checked_add(i64::MAX), None);
+    }
+
+    #[test]
+    fn test_text_and_serde() {
+        let day = DatePeriod::daily(2024, 60).unwrap();
+        let cases = [
+            (TimeSlot::hour(day, 13).unwrap(), "2024D60H13"),
+            (TimeSlot::new(day, 15, 53).unwrap(), "2024D60H13M15/15"),
+            (TimeSlot::new(day, 1440, 0).unwrap(), "2024D60H0M0/1440"),
+        ];
+        for (slot, text) in cases {
+            assert_eq!(slot.to_string(), text);
+            assert_eq!(text.parse::<TimeSlot>().unwrap(), slot);
+            let json = serde_json::to_string(&slot).unwrap();
+            assert_eq!(serde_json::from_str::<TimeSlot>(&json).unwrap(), slot);
+        }
+        assert_eq!("2024-02-29H13".parse::<TimeSlot>().unwrap(), cases[0].0);
+        for invalid in [
+            "2024D60",
+            "2024D60H24",
+            "2024D60H13M10/15",
+            "2024D60H13M60/1",
+            "2024M2H1",
+            "2024D60H1M0/0",
+        ] {
+            assert!(invalid.parse::<TimeSlot>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_instants() {
+        let hour = TimeSlot::hour_of(datetime(2024, 3, 10, 9, 30)).unwrap();
+        let tz = FixedOffset::east_opt(5 * 3600).unwrap();
+        let start = hour.start_instant(&tz).unwrap();
+        let end = hour.end_instant(&tz).unwrap();
+        assert_eq!(
+            start.with_timezone(&Utc).naive_utc(),
+            datetime(2024, 3, 10, 4, 0)
+        );
+        assert_eq!(end - start, chrono::Duration::hours(1));
+        assert_eq!(hour.start_instant(&Utc).unwrap().naive_utc(), hour.start());
+    }
+}
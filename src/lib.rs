@@ -5,6 +5,20 @@
 //! ## Main Components
 //!
 //! - [`range_type::DatePeriod`] - Enum defining date periods with embedded data (Year/Quarter/Month/Day)
+//! - [`range_type::PeriodKind`] - The granularity of a period (Year/Quarter/Month/Day)
+//! - [`period::Period`] - Trait implemented by all period types
+//! - [`typed`] - Per-granularity period types (`Year`, `Quarter`, `Month`, `Day`)
+//! - [`year_block::YearBlock`] - Decades, centuries and N-year blocks
+//! - [`intraday::TimeSlot`] - Hours and N-minute slots within a day
+//! - [`accounting::AccountingCalendar`] - 13-period (4-week) accounting calendars
+//! - [`broadcast::BroadcastPeriod`] - Broadcast calendar years, quarters, months and weeks
+//! - [`lunar::LunarPeriod`] - Chinese lunisolar years, months and days (1900-2100)
+//! - [`term::TermCalendar`] - Data-driven term calendars (e.g. academic terms)
+//! - [`cycle::Cycle`] - Fixed-length cycles anchored to a reference date
+//! - [`bucket`] - Batch assignment of dates to periods
+//! - [`recurrence::Recurrence`] - RRULE-style recurrence rules over periods
+//! - [`schedule::Schedule`] - Cron-like triggers for closed periods ("2nd business day after quarter end")
+//! - [`ical::IcsExporter`] - Export periods as iCalendar (RFC 5545) all-day events
 //! - [`leap_year`] - Utility function to determine if a year is a leap year
 //!
 //! ## Quick Example
@@ -28,7 +42,25 @@
 //!
 //! ```
 
+#![cfg_attr(
+    not(test),
+    deny(clippy::expect_used, clippy::panic, clippy::unwrap_used)
+)]
+
+pub mod accounting;
+pub mod broadcast;
+pub mod bucket;
+pub mod cycle;
+pub mod ical;
+pub mod intraday;
+pub mod lunar;
+pub mod period;
 pub mod range_type;
+pub mod recurrence;
+pub mod schedule;
+pub mod term;
+pub mod typed;
+pub mod year_block;
 
 /// Determines if a given year is a leap year
 ///
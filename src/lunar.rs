@@ -0,0 +1,891 @@
+//! Chinese lunisolar calendar periods.
+//!
+//! Lunar years begin at Spring Festival and have 12 or 13 months of 29 or 30
+//! days; the extra month of a 13-month year is a leap month that repeats the
+//! number of the month before it. [`LunarPeriod`] is a lunar year, month or
+//! day with Gregorian first and last days, date-to-period mapping and
+//! navigation, like [`DatePeriod`](crate::range_type::DatePeriod).
+//!
+//! Month lengths and leap months come from an embedded table covering the
+//! lunar years [`LunarPeriod::MIN_YEAR`] (1900) to [`LunarPeriod::MAX_YEAR`]
+//! (2100), computed with the rules of GB/T 33661-2017. Dates outside that
+//! range are rejected.
+//!
+//! ```rust
+//! use range_date::lunar::{LunarKind, LunarPeriod};
+//! use chrono::NaiveDate;
+//!
+//! let spring_festival = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+//! let year = LunarPeriod::from_date(spring_festival, LunarKind::Year).unwrap();
+//! assert_eq!(year.to_string(), "2024CY");
+//! assert_eq!(year.get_first_day().unwrap(), spring_festival);
+//!
+//! // 2023 had a leap second month
+//! let leap = LunarPeriod::month(2023, 2, true).unwrap();
+//! assert_eq!(leap.to_string(), "2023CM2L");
+//! assert_eq!(leap.get_first_day().unwrap(), NaiveDate::from_ymd_opt(2023, 3, 22).unwrap());
+//! assert_eq!(leap.succ().unwrap().to_string(), "2023CM3");
+//!
+//! let mid_autumn = LunarPeriod::day(2024, 8, false, 15).unwrap();
+//! assert_eq!(mid_autumn.get_first_day().unwrap(), NaiveDate::from_ymd_opt(2024, 9, 17).unwrap());
+//! ```
+
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::period::Period;
+
+/// Month data of the lunar years 1900-2100, one entry per year
+///
+/// Bits 0-3 hold the leap month (0 if none), bits 4-15 flag months 12 down
+/// to 1 as long (30 days), and bit 16 flags the leap month as long.
+const LUNAR_INFO: [u32; 201] = [
+    0x04bd8, 0x04ae0, 0x0a570, 0x054d5, 0x0d260, 0x0d950, 0x16554, 0x056a0, 0x09ad0,
+    0x055d2, // 1900-1909
+    0x04ae0, 0x0a5b6, 0x0a4d0, 0x0d250, 0x1d255, 0x0b540, 0x0d6a0, 0x0ada2, 0x095b0,
+    0x14977, // 1910-1919
+    0x04970, 0x0a4b0, 0x0b4b5, 0x06a50, 0x06d40, 0x1ab54, 0x02b60, 0x09570, 0x052f2,
+    0x04970, // 1920-1929
+    0x06566, 0x0d4a0, 0x0ea50, 0x16a95, 0x05ad0, 0x02b60, 0x186e3, 0x092e0, 0x1c8d7,
+    0x0c950, // 1930-1939
+    0x0d4a0, 0x1d8a6, 0x0b550, 0x056a0, 0x1a5b4, 0x025d0, 0x092d0, 0x0d2b2, 0x0a950,
+    0x0b557, // 1940-1949
+    0x06ca0, 0x0b550, 0x15355, 0x04da0, 0x0a5b0, 0x14573, 0x052b0, 0x0a9a8, 0x0e950,
+    0x06aa0, // 1950-1959
+    0x0aea6, 0x0ab50, 0x04b60, 0x0aae4, 0x0a570, 0x05260, 0x0f263, 0x0d950, 0x05b57,
+    0x056a0, // 1960-1969
+    0x096d0, 0x04dd5, 0x04ad0, 0x0a4d0, 0x0d4d4, 0x0d250, 0x0d558, 0x0b540, 0x0b6a0,
+    0x195a6, // 1970-1979
+    0x095b0, 0x049b0, 0x0a974, 0x0a4b0, 0x0b27a, 0x06a50, 0x06d40, 0x0af46, 0x0ab60,
+    0x09570, // 1980-1989
+    0x04af5, 0x04970, 0x064b0, 0x074a3, 0x0ea50, 0x06b58, 0x05ac0, 0x0ab60, 0x096d5,
+    0x092e0, // 1990-1999
+    0x0c960, 0x0d954, 0x0d4a0, 0x0da50, 0x07552, 0x056a0, 0x0abb7, 0x025d0, 0x092d0,
+    0x0cab5, // 2000-2009
+    0x0a950, 0x0b4a0, 0x0baa4, 0x0ad50, 0x055d9, 0x04ba0, 0x0a5b0, 0x15176, 0x052b0,
+    0x0a930, // 2010-2019
+    0x07954, 0x06aa0, 0x0ad50, 0x05b52, 0x04b60, 0x0a6e6, 0x0a4e0, 0x0d260, 0x0ea65,
+    0x0d530, // 2020-2029
+    0x05aa0, 0x076a3, 0x096d0, 0x04afb, 0x04ad0, 0x0a4d0, 0x1d0b6, 0x0d250, 0x0d520,
+    0x0dd45, // 2030-2039
+    0x0b5a0, 0x056d0, 0x055b2, 0x049b0, 0x0a577, 0x0a4b0, 0x0aa50, 0x1b255, 0x06d20,
+    0x0ada0, // 2040-2049
+    0x14b63, 0x09370, 0x049f8, 0x04970, 0x064b0, 0x168a6, 0x0ea50, 0x06aa0, 0x1a6c4,
+    0x0aae0, // 2050-2059
+    0x092e0, 0x0d2e3, 0x0c960, 0x0d557, 0x0d4a0, 0x0da50, 0x05d55, 0x056a0, 0x0a6d0,
+    0x055d4, // 2060-2069
+    0x052d0, 0x0a9b8, 0x0a950, 0x0b4a0, 0x0b6a6, 0x0ad50, 0x055a0, 0x0aba4, 0x0a5b0,
+    0x052b0, // 2070-2079
+    0x0b273, 0x06930, 0x07337, 0x06aa0, 0x0ad50, 0x14b55, 0x04b60, 0x0a570, 0x054e4,
+    0x0d160, // 2080-2089
+    0x0e968, 0x0d520, 0x0daa0, 0x16aa6, 0x056d0, 0x04ae0, 0x0a9d4, 0x0a2d0, 0x0d150,
+    0x0f252, // 2090-2099
+    0x0d520, // 2100
+];
+
+/// Days from the Gregorian epoch (0001-01-01) to the 1900 Spring Festival,
+/// 1900-01-31
+const EPOCH_DAYS: i32 = 693_626;
+
+/// Days from the 1900 Spring Festival to the start of each lunar year,
+/// plus the end of the last one
+const YEAR_OFFSETS: [u32; 202] = year_offsets();
+
+const fn year_days(info: u32) -> u32 {
+    let mut days = 12 * 29;
+    let mut mask = 0x8000;
+    while mask > 0x8 {
+        if info & mask != 0 {
+            days += 1;
+        }
+        mask >>= 1;
+    }
+    if info & 0xf != 0 {
+        days += if info & 0x10000 != 0 { 30 } else { 29 };
+    }
+    days
+}
+
+const fn year_offsets() -> [u32; 202] {
+    let mut offsets = [0; 202];
+    let mut i = 0;
+    while i < 201 {
+        offsets[i + 1] = offsets[i] + year_days(LUNAR_INFO[i]);
+        i += 1;
+    }
+    offsets
+}
+
+/// The granularity of a [`LunarPeriod`], ordered from coarsest to finest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LunarKind {
+    Year,
+    Month,
+    Day,
+}
+
+impl LunarKind {
+    /// Get the short name used in period strings (`Y`, `M` or `D`)
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            LunarKind::Year => "Y",
+            LunarKind::Month => "M",
+            LunarKind::Day => "D",
+        }
+    }
+
+    /// Get the next coarser kind, `None` for [`LunarKind::Year`]
+    pub fn parent(&self) -> Option<LunarKind> {
+        match self {
+            LunarKind::Year => None,
+            LunarKind::Month => Some(LunarKind::Year),
+            LunarKind::Day => Some(LunarKind::Month),
+        }
+    }
+
+    /// Get the next finer kind, `None` for [`LunarKind::Day`]
+    pub fn child(&self) -> Option<LunarKind> {
+        match self {
+            LunarKind::Year => Some(LunarKind::Month),
+            LunarKind::Month => Some(LunarKind::Day),
+            LunarKind::Day => None,
+        }
+    }
+}
+
+/// A lunar year, month or day
+///
+/// Months are identified by their number (1-12) and whether they are the
+/// leap month following the month of that number. The textual form is
+/// `2024CY`, `2023CM2`, `2023CM2L` (the leap second month) or `2024CM8D15`.
+/// Periods can only be built through the validating constructors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum LunarPeriod {
+    #[non_exhaustive]
+    Year(u32),
+    #[non_exhaustive]
+    Month(u32, u32, bool),
+    #[non_exhaustive]
+    Day(u32, u32, bool, u32),
+}
+
+impl LunarPeriod {
+    /// The first lunar year of the embedded table
+    pub const MIN_YEAR: u32 = 1900;
+
+    /// The last lunar year of the embedded table
+    pub const MAX_YEAR: u32 = 2100;
+
+    /// Create a lunar year with validation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `year` is outside
+    /// [`LunarPeriod::MIN_YEAR`]`..=`[`LunarPeriod::MAX_YEAR`].
+    pub fn year(year: u32) -> anyhow::Result<Self> {
+        info(year)?;
+        Ok(LunarPeriod::Year(year))
+    }
+
+    /// Create a lunar month with validation; `leap` selects the leap month
+    /// following month `month`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `year` is out of range, `month` is not in
+    /// `1..=12`, or `leap` is set but the year has no such leap month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::lunar::LunarPeriod;
+    ///
+    /// assert!(LunarPeriod::month(2023, 2, true).is_ok());
+    /// assert!(LunarPeriod::month(2024, 2, true).is_err());
+    /// assert_eq!(LunarPeriod::leap_month(2025).unwrap(), Some(6));
+    /// ```
+    pub fn month(year: u32, month: u32, leap: bool) -> anyhow::Result<Self> {
+        let info = info(year)?;
+        if !(1..=12).contains(&month) {
+            return Err(anyhow::anyhow!("Lunar month must be 1-12, got: {}", month));
+        }
+        if leap && info & 0xf != month {
+            return Err(anyhow::anyhow!(
+                "Lunar year {} has no leap month {}",
+                year,
+                month
+            ));
+        }
+        Ok(LunarPeriod::Month(year, month, leap))
+    }
+
+    /// Create a lunar day with validation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the month is invalid (see [`LunarPeriod::month`])
+    /// or `day` is not in `1..=29` (`1..=30` in long months).
+    pub fn day(year: u32, month: u32, leap: bool, day: u32) -> anyhow::Result<Self> {
+        let days = Self::month(year, month, leap)?.days();
+        if !(1..=days).contains(&day) {
+            return Err(anyhow::anyhow!(
+                "Day must be 1-{} in lunar month {}, got: {}",
+                days,
+                Self::Month(year, month, leap),
+                day
+            ));
+        }
+        Ok(LunarPeriod::Day(year, month, leap, day))
+    }
+
+    /// Get the leap month of the lunar year `year`, `None` if it has none
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `year` is out of range.
+    pub fn leap_month(year: u32) -> anyhow::Result<Option<u32>> {
+        let leap = info(year)? & 0xf;
+        Ok((leap != 0).then_some(leap))
+    }
+
+    /// Parse a lunar period from its string form, e.g. `2023CM2L`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is malformed or the period fails
+    /// validation.
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let invalid = || anyhow::anyhow!("Invalid lunar period format: {}", s);
+        let number = |digits: &str| {
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            digits.parse::<u32>().map_err(|_| invalid())
+        };
+        let s = s.trim();
+        let (year, rest) = s.split_once('C').ok_or_else(invalid)?;
+        let year = number(year)?;
+        if rest == "Y" {
+            return Self::year(year);
+        }
+        let rest = rest.strip_prefix('M').ok_or_else(invalid)?;
+        let (month, day) = match rest.split_once('D') {
+            Some((month, day)) => (month, Some(number(day)?)),
+            None => (rest, None),
+        };
+        let (month, leap) = match month.strip_suffix('L') {
+            Some(month) => (number(month)?, true),
+            None => (number(month)?, false),
+        };
+        match day {
+            Some(day) => Self::day(year, month, leap, day),
+            None => Self::month(year, month, leap),
+        }
+    }
+
+    /// Create the lunar period of the given kind containing `date`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `date` lies outside the lunar years
+    /// [`LunarPeriod::MIN_YEAR`]`..=`[`LunarPeriod::MAX_YEAR`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::lunar::{LunarKind, LunarPeriod};
+    /// use chrono::NaiveDate;
+    ///
+    /// // The day before Spring Festival 2024 is the last day of lunar 2023
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 9).unwrap();
+    /// let day = LunarPeriod::from_date(date, LunarKind::Day).unwrap();
+    /// assert_eq!(day.to_string(), "2023CM12D30");
+    /// ```
+    pub fn from_date(date: NaiveDate, kind: LunarKind) -> anyhow::Result<Self> {
+        let out_of_range = || {
+            anyhow::anyhow!(
+                "Date {} is outside the lunar years {}-{}",
+                date,
+                Self::MIN_YEAR,
+                Self::MAX_YEAR
+            )
+        };
+        let offset = u32::try_from(date.num_days_from_ce() - EPOCH_DAYS)
+            .ok()
+            .filter(|offset| *offset < YEAR_OFFSETS[201])
+            .ok_or_else(out_of_range)?;
+        let index = YEAR_OFFSETS.partition_point(|start| *start <= offset) - 1;
+        let year = Self::MIN_YEAR + index as u32;
+        if kind == LunarKind::Year {
+            return Self::year(year);
+        }
+        let mut day = offset - YEAR_OFFSETS[index];
+        for month in months(LUNAR_INFO[index]) {
+            if day < month.days {
+                return match kind {
+                    LunarKind::Month => Self::month(year, month.number, month.leap),
+                    _ => Self::day(year, month.number, month.leap, day + 1),
+                };
+            }
+            day -= month.days;
+        }
+        Err(out_of_range())
+    }
+
+    /// Generate all lunar periods of `kind` between two dates (inclusive)
+    /// Returns an empty vector if start > end
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either date lies outside the supported years.
+    pub fn between(
+        start: NaiveDate,
+        end: NaiveDate,
+        kind: LunarKind,
+    ) -> anyhow::Result<Vec<LunarPeriod>> {
+        if start > end {
+            return Ok(vec![]);
+        }
+        let mut current = Self::from_date(start, kind)?;
+        let last = Self::from_date(end, kind)?;
+        let mut periods = vec![current];
+        while current < last {
+            current = current.succ()?;
+            periods.push(current);
+        }
+        Ok(periods)
+    }
+
+    /// Get the Gregorian first day of the period
+    ///
+    /// # Errors
+    ///
+    /// Never fails for periods built by the constructors; the `Result`
+    /// matches [`DatePeriod::get_first_day`](crate::range_type::DatePeriod::get_first_day).
+    pub fn get_first_day(&self) -> anyhow::Result<NaiveDate> {
+        let (first, _) = self.bounds();
+        gregorian(first)
+    }
+
+    /// Get the Gregorian last day of the period (inclusive)
+    ///
+    /// # Errors
+    ///
+    /// See [`LunarPeriod::get_first_day`].
+    pub fn get_last_day(&self) -> anyhow::Result<NaiveDate> {
+        let (first, days) = self.bounds();
+        gregorian(first + days - 1)
+    }
+
+    /// Check if `date` lies within the period
+    pub fn contains_date(&self, date: NaiveDate) -> bool {
+        let (first, days) = self.bounds();
+        let offset = date.num_days_from_ce() as i64 - EPOCH_DAYS as i64;
+        first as i64 <= offset && offset < (first + days) as i64
+    }
+
+    /// Get the lunar year of the period
+    pub fn get_year(&self) -> u32 {
+        match self {
+            LunarPeriod::Year(year) | LunarPeriod::Month(year, ..) | LunarPeriod::Day(year, ..) => {
+                *year
+            }
+        }
+    }
+
+    /// Get the month number (1-12), `None` for yearly periods
+    pub fn get_month(&self) -> Option<u32> {
+        match self {
+            LunarPeriod::Year(_) => None,
+            LunarPeriod::Month(_, month, _) | LunarPeriod::Day(_, month, _, _) => Some(*month),
+        }
+    }
+
+    /// Check if the period is, or lies in, a leap month
+    pub fn is_leap_month(&self) -> bool {
+        matches!(
+            self,
+            LunarPeriod::Month(_, _, true) | LunarPeriod::Day(_, _, true, _)
+        )
+    }
+
+    /// Get the day of the month, `None` for years and months
+    pub fn get_day_of_month(&self) -> Option<u32> {
+        match self {
+            LunarPeriod::Day(.., day) => Some(*day),
+            _ => None,
+        }
+    }
+
+    /// Get the kind of the period
+    pub fn kind(&self) -> LunarKind {
+        match self {
+            LunarPeriod::Year(_) => LunarKind::Year,
+            LunarPeriod::Month(..) => LunarKind::Month,
+            LunarPeriod::Day(..) => LunarKind::Day,
+        }
+    }
+
+    /// Get the number of days in the period
+    pub fn days(&self) -> u32 {
+        self.bounds().1
+    }
+
+    /// Get the next period of the same kind
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the next period lies after
+    /// [`LunarPeriod::MAX_YEAR`].
+    pub fn succ(&self) -> anyhow::Result<Self> {
+        self.offset_n(1)
+    }
+
+    /// Get the previous period of the same kind
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the previous period lies before
+    /// [`LunarPeriod::MIN_YEAR`].
+    pub fn pred(&self) -> anyhow::Result<Self> {
+        self.offset_n(-1)
+    }
+
+    /// Get the period `n` steps away, backwards if `n` is negative
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result lies outside the supported years.
+    pub fn offset_n(&self, n: i32) -> anyhow::Result<Self> {
+        self.checked_add(n as i64)
+            .ok_or_else(|| anyhow::anyhow!("{} offset by {} is out of range", self, n))
+    }
+
+    /// Get the period `n` steps away, `None` if out of range
+    ///
+    /// Months step through leap months, so the month after `2023CM2` is
+    /// `2023CM2L`.
+    pub fn checked_add(&self, n: i64) -> Option<Self> {
+        match *self {
+            LunarPeriod::Year(year) => {
+                let year = u32::try_from((year as i64).checked_add(n)?).ok()?;
+                Self::year(year).ok()
+            }
+            LunarPeriod::Month(..) => {
+                let mut position = self.month_position().checked_add(n)?;
+                if position < 0 {
+                    return None;
+                }
+                for (index, info) in LUNAR_INFO.iter().enumerate() {
+                    let months = months(*info);
+                    let count = months.len() as i64;
+                    if position < count {
+                        let month = months[position as usize];
+                        return Self::month(
+                            Self::MIN_YEAR + index as u32,
+                            month.number,
+                            month.leap,
+                        )
+                        .ok();
+                    }
+                    position -= count;
+                }
+                None
+            }
+            LunarPeriod::Day(..) => {
+                let (first, _) = self.bounds();
+                let offset = i64::from(first).checked_add(n)?;
+                let date = gregorian(u32::try_from(offset).ok()?).ok()?;
+                Self::from_date(date, LunarKind::Day).ok()
+            }
+        }
+    }
+
+    /// Get the period of the next coarser kind containing this one
+    ///
+    /// Years have no parent and return themselves.
+    pub fn aggregate(&self) -> Self {
+        match *self {
+            LunarPeriod::Year(_) => *self,
+            LunarPeriod::Month(year, ..) => LunarPeriod::Year(year),
+            LunarPeriod::Day(year, month, leap, _) => LunarPeriod::Month(year, month, leap),
+        }
+    }
+
+    /// Decompose the period into periods of the next finer kind
+    ///
+    /// A year yields its 12 or 13 months in order, a month its days, and a
+    /// day an empty vector.
+    pub fn decompose(&self) -> Vec<Self> {
+        match *self {
+            LunarPeriod::Year(year) => info(year)
+                .map(|info| {
+                    months(info)
+                        .iter()
+                        .map(|month| LunarPeriod::Month(year, month.number, month.leap))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            LunarPeriod::Month(year, month, leap) => (1..=self.days())
+                .map(|day| LunarPeriod::Day(year, month, leap, day))
+                .collect(),
+            LunarPeriod::Day(..) => vec![],
+        }
+    }
+
+    /// Days from the 1900 Spring Festival to the first day, and the length
+    fn bounds(&self) -> (u32, u32) {
+        let year = self.get_year();
+        let index = (year.saturating_sub(Self::MIN_YEAR) as usize).min(200);
+        let year_start = YEAR_OFFSETS[index];
+        let (number, leap) = match *self {
+            LunarPeriod::Year(_) => return (year_start, YEAR_OFFSETS[index + 1] - year_start),
+            LunarPeriod::Month(_, number, leap) | LunarPeriod::Day(_, number, leap, _) => {
+                (number, leap)
+            }
+        };
+        let mut first = year_start;
+        let mut days = 0;
+        for month in months(LUNAR_INFO[index]) {
+            if month.number == number && month.leap == leap {
+                days = month.days;
+                break;
+            }
+            first += month.days;
+        }
+        match self.get_day_of_month() {
+            Some(day) => (first + day - 1, 1),
+            None => (first, days),
+        }
+    }
+
+    /// Months between the first month of [`LunarPeriod::MIN_YEAR`] and this
+    /// month
+    fn month_position(&self) -> i64 {
+        let year = self.get_year();
+        let index = (year.saturating_sub(Self::MIN_YEAR) as usize).min(200);
+        let before: usize = LUNAR_INFO[..index]
+            .iter()
+            .map(|info| months(*info).len())
+            .sum();
+        let within = months(LUNAR_INFO[index])
+            .iter()
+            .position(|month| {
+                Some(month.number) == self.get_month() && month.leap == self.is_leap_month()
+            })
+            .unwrap_or(0);
+        (before + within) as i64
+    }
+}
+
+/// One month of a lunar year
+#[derive(Debug, Clone, Copy)]
+struct LunarMonth {
+    number: u32,
+    leap: bool,
+    days: u32,
+}
+
+/// Table entry of the lunar year `year`
+fn info(year: u32) -> anyhow::Result<u32> {
+    year.checked_sub(LunarPeriod::MIN_YEAR)
+        .and_then(|index| LUNAR_INFO.get(index as usize))
+        .copied()
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Lunar year must be {}-{}, got: {}",
+                LunarPeriod::MIN_YEAR,
+                LunarPeriod::MAX_YEAR,
+                year
+            )
+        })
+}
+
+/// Months of a year in order, including the leap month
+fn months(info: u32) -> Vec<LunarMonth> {
+    let leap_month = info & 0xf;
+    let mut months = Vec::with_capacity(13);
+    for number in 1..=12 {
+        let days = if info & (0x10000 >> number) != 0 {
+            30
+        } else {
+            29
+        };
+        months.push(LunarMonth {
+            number,
+            leap: false,
+            days,
+        });
+        if number == leap_month {
+            let days = if info & 0x10000 != 0 { 30 } else { 29 };
+            months.push(LunarMonth {
+                number,
+                leap: true,
+                days,
+            });
+        }
+    }
+    months
+}
+
+/// Gregorian date `offset` days after the 1900 Spring Festival
+fn gregorian(offset: u32) -> anyhow::Result<NaiveDate> {
+    NaiveDate::from_ymd_opt(1900, 1, 31)
+        .and_then(|epoch| epoch.checked_add_signed(Duration::try_days(offset as i64)?))
+        .ok_or_else(|| anyhow::anyhow!("Lunar day {} is out of range", offset))
+}
+
+impl std::fmt::Display for LunarPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            LunarPeriod::Year(year) => write!(f, "{}CY", year),
+            LunarPeriod::Month(year, month, leap) => {
+                write!(f, "{}CM{}{}", year, month, if leap { "L" } else { "" })
+            }
+            LunarPeriod::Day(year, month, leap, day) => write!(
+                f,
+                "{}CM{}{}D{}",
+                year,
+                month,
+                if leap { "L" } else { "" },
+                day
+            ),
+        }
+    }
+}
+
+impl std::str::FromStr for LunarPeriod {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Serialize for LunarPeriod {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LunarPeriod {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<LunarPeriod, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        LunarPeriod::parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Period for LunarPeriod {
+    type Parent = LunarPeriod;
+    type Child = LunarPeriod;
+
+    fn get_first_day(&self) -> anyhow::Result<NaiveDate> {
+        LunarPeriod::get_first_day(self)
+    }
+
+    fn get_last_day(&self) -> anyhow::Result<NaiveDate> {
+        LunarPeriod::get_last_day(self)
+    }
+
+    fn checked_add(&self, n: i64) -> Option<Self> {
+        LunarPeriod::checked_add(self, n)
+    }
+
+    fn containing(&self, date: NaiveDate) -> anyhow::Result<Self> {
+        LunarPeriod::from_date(date, self.kind())
+    }
+
+    fn parent(&self) -> Option<Self> {
+        self.kind().parent().map(|_| self.aggregate())
+    }
+
+    fn children(&self) -> Vec<Self> {
+        self.decompose()
+    }
+
+    fn contains_date(&self, date: NaiveDate) -> bool {
+        LunarPeriod::contains_date(self, date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_table_integrity() {
+        assert_eq!(gregorian(0).unwrap().num_days_from_ce(), EPOCH_DAYS);
+        for year in LunarPeriod::MIN_YEAR..=LunarPeriod::MAX_YEAR {
+            let period = LunarPeriod::year(year).unwrap();
+            let new_year = period.get_first_day().unwrap();
+            // Spring Festival always falls between January 21st and February 20th
+            assert!(
+                (date(year as i32, 1, 21)..=date(year as i32, 2, 20)).contains(&new_year),
+                "{} starts on {}",
+                period,
+                new_year
+            );
+            let months = period.decompose();
+            let leap = LunarPeriod::leap_month(year).unwrap();
+            assert_eq!(months.len(), if leap.is_some() { 13 } else { 12 });
+            assert!(months.iter().all(|m| m.days() == 29 || m.days() == 30));
+            assert!((353..=385).contains(&period.days()));
+        }
+    }
+
+    #[test]
+    fn test_known_dates() {
+        let spring_festivals = [
+            (1900, date(1900, 1, 31)),
+            (1912, date(1912, 2, 18)),
+            (1949, date(1949, 1, 29)),
+            (1984, date(1984, 2, 2)),
+            (2000, date(2000, 2, 5)),
+            (2020, date(2020, 1, 25)),
+            (2023, date(2023, 1, 22)),
+            (2024, date(2024, 2, 10)),
+            (2025, date(2025, 1, 29)),
+            (2061, date(2061, 1, 21)),
+            (2100, date(2100, 2, 9)),
+        ];
+        for (year, first_day) in spring_festivals {
+            assert_eq!(
+                LunarPeriod::year(year).unwrap().get_first_day().unwrap(),
+                first_day
+            );
+            let day = LunarPeriod::from_date(first_day, LunarKind::Day).unwrap();
+            assert_eq!(day, LunarPeriod::day(year, 1, false, 1).unwrap());
+        }
+        // Mid-Autumn Festival (8/15) and Dragon Boat Festival (5/5)
+        assert_eq!(
+            LunarPeriod::day(2024, 8, false, 15)
+                .unwrap()
+                .get_first_day()
+                .unwrap(),
+            date(2024, 9, 17)
+        );
+        assert_eq!(
+            LunarPeriod::day(2025, 5, false, 5)
+                .unwrap()
+                .get_first_day()
+                .unwrap(),
+            date(2025, 5, 31)
+        );
+        // Leap months: 2020 had a leap fourth month, 2033 a leap eleventh
+        assert_eq!(LunarPeriod::leap_month(2020).unwrap(), Some(4));
+        assert_eq!(LunarPeriod::leap_month(2033).unwrap(), Some(11));
+        assert_eq!(LunarPeriod::leap_month(2024).unwrap(), None);
+        assert_eq!(
+            LunarPeriod::month(2020, 4, true)
+                .unwrap()
+                .get_first_day()
+                .unwrap(),
+            date(2020, 5, 23)
+        );
+    }
+
+    #[test]
+    fn test_periods_tile_the_calendar() {
+        for kind in [LunarKind::Year, LunarKind::Month, LunarKind::Day] {
+            let mut day = date(2019, 12, 1);
+            let mut previous = LunarPeriod::from_date(day, kind).unwrap();
+            while day < date(2026, 3, 1) {
+                let period = LunarPeriod::from_date(day, kind).unwrap();
+                assert!(period.contains_date(day));
+                if period != previous {
+                    assert_eq!(previous.succ().unwrap(), period);
+                    assert_eq!(period.pred().unwrap(), previous);
+                    assert_eq!(period.get_first_day().unwrap(), day);
+                    assert_eq!(previous.get_last_day().unwrap(), day.pred_opt().unwrap());
+                    assert!(
+                        period.aggregate().decompose().contains(&period) || kind == LunarKind::Year
+                    );
+                }
+                previous = period;
+                day = day.succ_opt().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_navigation_and_range() {
+        let month = LunarPeriod::month(2023, 2, false).unwrap();
+        assert_eq!(
+            month.succ().unwrap(),
+            LunarPeriod::month(2023, 2, true).unwrap()
+        );
+        assert_eq!(
+            month.offset_n(12).unwrap(),
+            LunarPeriod::month(2024, 1, false).unwrap()
+        );
+        assert_eq!(
+            LunarPeriod::month(2024, 1, false)
+                .unwrap()
+                .offset_n(-12)
+                .unwrap(),
+            month
+        );
+
+        let first = LunarPeriod::day(1900, 1, false, 1).unwrap();
+        assert!(first.pred().is_err());
+        assert!(LunarPeriod::month(1900, 1, false).unwrap().pred().is_err());
+        let last = LunarPeriod::from_date(
+            LunarPeriod::year(2100).unwrap().get_last_day().unwrap(),
+            LunarKind::Day,
+        )
+        .unwrap();
+        assert!(last.succ().is_err());
+        assert!(LunarPeriod::month(2100, 12, false).unwrap().succ().is_err());
+        assert_eq!(first.checked_add(i64::MAX), None);
+        assert!(LunarPeriod::from_date(date(1900, 1, 30), LunarKind::Day).is_err());
+        assert!(LunarPeriod::year(2101).is_err());
+
+        let months =
+            LunarPeriod::between(date(2023, 3, 1), date(2023, 5, 1), LunarKind::Month).unwrap();
+        assert_eq!(
+            months
+                .iter()
+                .map(LunarPeriod::to_string)
+                .collect::<Vec<_>>(),
+            ["2023CM2", "2023CM2L", "2023CM3"]
+        );
+        let generic: Vec<_> =
+            crate::period::between(&months[0], date(2023, 3, 1), date(2023, 5, 1))
+                .unwrap()
+                .collect();
+        assert_eq!(generic, months);
+    }
+
+    #[test]
+    fn test_text_and_serde() {
+        for text in ["2024CY", "2023CM2", "2023CM2L", "2024CM8D15", "2023CM2LD29"] {
+            let period = LunarPeriod::parse(text).unwrap();
+            assert_eq!(period.to_string(), text);
+            let json = serde_json::to_string(&period).unwrap();
+            assert_eq!(serde_json::from_str::<LunarPeriod>(&json).unwrap(), period);
+        }
+        for invalid in [
+            "2024Y",
+            "2024CM13",
+            "2024CM2L",
+            "2024CM1D31",
+            "2024CY1",
+            "1899CY",
+            "2024CMD1",
+            "2024CM+1",
+        ] {
+            assert!(LunarPeriod::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,396 @@
+//! The [`Period`] trait shared by all period types.
+//!
+//! [`DatePeriod`](crate::range_type::DatePeriod) and the typed periods in
+//! [`crate::typed`] implement it, and so can application-defined calendars
+//! (billing cycles, sprints, ...). Algorithms over contiguous date ranges can
+//! then be written once, and [`range`] / [`between`] generate ranges of any
+//! period type:
+//!
+//! ```rust
+//! use range_date::period::Period;
+//! use range_date::typed::{Month, Quarter};
+//!
+//! fn days_in<P: Period>(period: &P) -> anyhow::Result<i64> {
+//!     Ok((period.get_last_day()? - period.get_first_day()?).num_days() + 1)
+//! }
+//!
+//! assert_eq!(days_in(&Month::new(2024, 2).unwrap()).unwrap(), 29);
+//! assert_eq!(days_in(&Quarter::new(2023, 1).unwrap()).unwrap(), 90);
+//! ```
+//!
+//! A custom calendar only needs its boundaries, a step function and a date
+//! lookup:
+//!
+//! ```rust
+//! use std::fmt;
+//! use std::str::FromStr;
+//! use chrono::{Datelike, NaiveDate};
+//! use range_date::period::{self, Period};
+//!
+//! /// Half years, e.g. `2024H2`
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+//! struct Half(i32, u32);
+//!
+//! impl fmt::Display for Half {
+//!     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!         write!(f, "{}H{}", self.0, self.1)
+//!     }
+//! }
+//!
+//! impl FromStr for Half {
+//!     type Err = anyhow::Error;
+//!     fn from_str(s: &str) -> anyhow::Result<Self> {
+//!         let (year, half) = s.split_once('H').ok_or_else(|| anyhow::anyhow!("bad half"))?;
+//!         Ok(Half(year.parse()?, half.parse()?))
+//!     }
+//! }
+//!
+//! impl Period for Half {
+//!     type Parent = Half;
+//!     type Child = Half;
+//!
+//!     fn get_first_day(&self) -> anyhow::Result<NaiveDate> {
+//!         NaiveDate::from_ymd_opt(self.0, self.1 * 6 - 5, 1).ok_or_else(|| anyhow::anyhow!("out of range"))
+//!     }
+//!     fn get_last_day(&self) -> anyhow::Result<NaiveDate> {
+//!         Ok(self.checked_add(1).ok_or_else(|| anyhow::anyhow!("out of range"))?.get_first_day()?.pred_opt().unwrap())
+//!     }
+//!     fn checked_add(&self, n: i64) -> Option<Self> {
+//!         let index = (self.0 as i64 * 2 + self.1 as i64 - 1).checked_add(n)?;
+//!         Some(Half(i32::try_from(index.div_euclid(2)).ok()?, index.rem_euclid(2) as u32 + 1))
+//!     }
+//!     fn containing(&self, date: NaiveDate) -> anyhow::Result<Self> {
+//!         Ok(Half(date.year(), if date.month() <= 6 { 1 } else { 2 }))
+//!     }
+//! }
+//!
+//! let start = NaiveDate::from_ymd_opt(2023, 5, 1).unwrap();
+//! let end = NaiveDate::from_ymd_opt(2024, 8, 1).unwrap();
+//! let halves: Vec<_> = period::between(&Half(0, 1), start, end).unwrap().collect();
+//! assert_eq!(halves, [Half(2023, 1), Half(2023, 2), Half(2024, 1), Half(2024, 2)]);
+//! assert!(halves[1].contains_date(NaiveDate::from_ymd_opt(2023, 12, 31).unwrap()));
+//! ```
+
+use std::fmt;
+use std::iter::FusedIterator;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+
+/// A contiguous, inclusive range of days that can be navigated step by step
+///
+/// Implementors provide the boundaries, [`Period::checked_add`] and
+/// [`Period::containing`]; containment and the fallible navigation methods
+/// are derived from them. Periods with a coarser or finer level override
+/// [`Period::parent`] / [`Period::children`]; others can set both associated
+/// types to `Self` and keep the defaults. `Display` and `FromStr` give the
+/// textual form.
+pub trait Period: Copy + Ord + fmt::Debug + fmt::Display + FromStr {
+    /// The type of the enclosing period, see [`Period::parent`]
+    type Parent: Period;
+
+    /// The type of the sub-periods, see [`Period::children`]
+    type Child: Period;
+
+    /// Get the first day of the period
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the day cannot be represented as a [`NaiveDate`].
+    fn get_first_day(&self) -> anyhow::Result<NaiveDate>;
+
+    /// Get the last day of the period (inclusive)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the day cannot be represented as a [`NaiveDate`].
+    fn get_last_day(&self) -> anyhow::Result<NaiveDate>;
+
+    /// Offset the period by `n` steps, or `None` if the result is not
+    /// representable
+    fn checked_add(&self, n: i64) -> Option<Self>;
+
+    /// Get the period of the same family (kind, cycle, ...) as this one that
+    /// contains `date`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no such period is representable.
+    fn containing(&self, date: NaiveDate) -> anyhow::Result<Self>;
+
+    /// Get the last period of the same family that covers part of `date`
+    ///
+    /// This is [`Period::containing`] for periods of a day or longer, which
+    /// [`between`] relies on to include the whole of its last day. Periods
+    /// shorter than a day return the last one of the day.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no such period is representable.
+    fn containing_last(&self, date: NaiveDate) -> anyhow::Result<Self> {
+        self.containing(date)
+    }
+
+    /// Get the enclosing period one level up, or `None` at the top level
+    fn parent(&self) -> Option<Self::Parent> {
+        None
+    }
+
+    /// Get the sub-periods one level down, empty at the bottom level
+    fn children(&self) -> Vec<Self::Child> {
+        Vec::new()
+    }
+
+    /// Check if `date` lies within the period
+    fn contains_date(&self, date: NaiveDate) -> bool {
+        match (self.get_first_day(), self.get_last_day()) {
+            (Ok(first_day), Ok(last_day)) => first_day <= date && date <= last_day,
+            _ => false,
+        }
+    }
+
+    /// Check if every day of `other` lies within this period
+    fn contains_period<P: Period>(&self, other: &P) -> bool {
+        match (other.get_first_day(), other.get_last_day()) {
+            (Ok(first_day), Ok(last_day)) => {
+                self.contains_date(first_day) && self.contains_date(last_day)
+            }
+            _ => false,
+        }
+    }
+
+    /// Check if this period and `other` share at least one day
+    fn overlaps<P: Period>(&self, other: &P) -> bool {
+        match (
+            self.get_first_day(),
+            self.get_last_day(),
+            other.get_first_day(),
+            other.get_last_day(),
+        ) {
+            (Ok(first_day), Ok(last_day), Ok(other_first), Ok(other_last)) => {
+                first_day <= other_last && other_first <= last_day
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the successor (next) period
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no next period.
+    fn succ(&self) -> anyhow::Result<Self> {
+        self.succ_n(1)
+    }
+
+    /// Get the predecessor (previous) period
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no previous period.
+    fn pred(&self) -> anyhow::Result<Self> {
+        self.pred_n(1)
+    }
+
+    /// Get the successor n periods ahead
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result is not representable.
+    fn succ_n(&self, n: u32) -> anyhow::Result<Self> {
+        self.checked_add(n as i64)
+            .ok_or_else(|| anyhow::anyhow!("Cannot go forward {} periods from {}", n, self))
+    }
+
+    /// Get the predecessor n periods back
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result is not representable.
+    fn pred_n(&self, n: u32) -> anyhow::Result<Self> {
+        self.checked_add(-(n as i64))
+            .ok_or_else(|| anyhow::anyhow!("Cannot go back {} periods from {}", n, self))
+    }
+
+    /// Offset the period by n steps, forwards for positive n
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result is not representable.
+    fn offset_n(&self, n: i32) -> anyhow::Result<Self> {
+        self.checked_add(n as i64)
+            .ok_or_else(|| anyhow::anyhow!("Cannot offset {} by {} periods", self, n))
+    }
+}
+
+/// Iterate over the periods from `start` to `end` (inclusive)
+///
+/// `start` and `end` should belong to the same family (e.g. both months).
+/// The iterator is empty if `start > end` and stops early if a step is not
+/// representable.
+///
+/// # Examples
+///
+/// ```
+/// use range_date::period;
+/// use range_date::typed::Month;
+///
+/// let months: Vec<_> = period::range(Month::new(2024, 11).unwrap(), Month::new(2025, 2).unwrap()).collect();
+/// assert_eq!(months.len(), 4);
+/// ```
+pub fn range<P: Period>(start: P, end: P) -> PeriodRange<P> {
+    PeriodRange {
+        next: (start <= end).then_some(start),
+        end,
+    }
+}
+
+/// Iterate over the periods of `family` covering `start..=end`
+///
+/// `family` only selects the kind of period (e.g. a month, or a billing cycle
+/// with its anchor); its own position does not matter. The iterator is empty
+/// if `start > end`.
+///
+/// # Errors
+///
+/// Returns an error if `start <= end` and the period containing `start` or
+/// `end` cannot be determined.
+///
+/// # Examples
+///
+/// ```
+/// use range_date::period;
+/// use range_date::range_type::DatePeriod;
+/// use chrono::NaiveDate;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+/// let quarters: Vec<_> = period::between(&DatePeriod::quarter(2000, 1).unwrap(), start, end)
+///     .unwrap()
+///     .collect();
+/// assert_eq!(quarters.len(), 3);
+/// ```
+pub fn between<P: Period>(
+    family: &P,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> anyhow::Result<PeriodRange<P>> {
+    if start > end {
+        return Ok(PeriodRange {
+            next: None,
+            end: *family,
+        });
+    }
+    Ok(range(
+        family.containing(start)?,
+        family.containing_last(end)?,
+    ))
+}
+
+/// Iterator over consecutive periods, created by [`range`] and [`between`]
+#[derive(Debug, Clone)]
+pub struct PeriodRange<P> {
+    next: Option<P>,
+    end: P,
+}
+
+impl<P: Period> Iterator for PeriodRange<P> {
+    type Item = P;
+
+    fn next(&mut self) -> Option<P> {
+        let current = self.next?;
+        self.next = current.checked_add(1).filter(|next| *next <= self.end);
+        Some(current)
+    }
+}
+
+impl<P: Period> FusedIterator for PeriodRange<P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::range_type::{DatePeriod, PeriodKind};
+    use crate::typed::{Day, Month, Quarter, Year};
+
+    /// Count the leaf periods below `period` through the generic hierarchy.
+    fn count_days<P: Period>(period: &P) -> usize {
+        let children = period.children();
+        if children.is_empty() {
+            1
+        } else {
+            children.iter().map(count_days).sum()
+        }
+    }
+
+    #[test]
+    fn test_generic_range_and_between() {
+        let start = NaiveDate::from_ymd_opt(2023, 12, 30).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 3, 2).unwrap();
+        for kind in PeriodKind::ALL {
+            let family = DatePeriod::from_date(start, kind).unwrap();
+            let generic: Vec<_> = between(&family, start, end).unwrap().collect();
+            assert_eq!(generic, DatePeriod::between(start, end, kind).unwrap());
+            assert_eq!(
+                range(generic[0], *generic.last().unwrap()).collect::<Vec<_>>(),
+                generic
+            );
+        }
+        let months: Vec<Month> = between(&Month::new(2000, 1).unwrap(), start, end)
+            .unwrap()
+            .collect();
+        assert_eq!(months, Month::between(start, end).unwrap());
+
+        assert_eq!(
+            between(&Year::new(2024).unwrap(), end, start)
+                .unwrap()
+                .count(),
+            0
+        );
+        // An empty range never looks up its bounds, like DatePeriod::between
+        let bce = NaiveDate::from_ymd_opt(-1, 6, 1).unwrap();
+        assert!(
+            DatePeriod::between(start, bce, PeriodKind::Month)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            between(&Month::new(2024, 1).unwrap(), start, bce)
+                .unwrap()
+                .count(),
+            0
+        );
+        assert!(between(&Month::new(2024, 1).unwrap(), bce, start).is_err());
+        let last = Year::new(DatePeriod::MAX_YEAR).unwrap();
+        assert_eq!(range(last, last).collect::<Vec<_>>(), vec![last]);
+        assert_eq!(range(last, last.pred().unwrap()).count(), 0);
+    }
+
+    #[test]
+    fn test_hierarchy_and_containment() {
+        let year = DatePeriod::year(2024).unwrap();
+        assert_eq!(year.parent(), None);
+        assert_eq!(count_days(&year), 366);
+        assert_eq!(count_days(&Year::new(2023).unwrap()), 365);
+
+        let day = Day::new(2024, 60).unwrap();
+        assert_eq!(day.parent(), Some(Month::new(2024, 2).unwrap()));
+        assert_eq!(
+            day.parent().and_then(|m| m.parent()),
+            Some(Quarter::new(2024, 1).unwrap())
+        );
+        assert!(day.children().is_empty());
+
+        let quarter = Quarter::new(2024, 1).unwrap();
+        assert!(quarter.contains_period(&day));
+        assert!(year.contains_period(&quarter));
+        assert!(!quarter.contains_period(&year));
+        assert!(quarter.overlaps(&year));
+        assert!(!quarter.overlaps(&Month::new(2024, 4).unwrap()));
+        assert_eq!(
+            quarter
+                .containing(NaiveDate::from_ymd_opt(2025, 5, 5).unwrap())
+                .unwrap(),
+            Quarter::new(2025, 2).unwrap()
+        );
+    }
+}
@@ -1,20 +1,81 @@
-use chrono::{Datelike, Duration, Months, NaiveDate};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use chrono::{
+    DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+    Weekday,
+};
 use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::leap_year;
-
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+use crate::period::Period;
+
+/// A date period with its embedded year and index
+///
+/// Values can only be created through the validated constructors
+/// ([`DatePeriod::year`], [`DatePeriod::quarter`], [`DatePeriod::month`],
+/// [`DatePeriod::daily`]), parsing, deserialization or the `from_date_as_*`
+/// conversions, so every `DatePeriod` refers to a real period between
+/// [`DatePeriod::MIN_YEAR`] and [`DatePeriod::MAX_YEAR`]. The variants can
+/// still be matched on outside this crate, naming the fields by position
+/// and ending the pattern with `..`:
+///
+/// ```
+/// use range_date::range_type::DatePeriod;
+///
+/// let period = DatePeriod::month(2024, 3).unwrap();
+/// match period {
+///     DatePeriod::Month { 0: year, 1: month, .. } => assert_eq!((year, month), (2024, 3)),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
 pub enum DatePeriod {
     /// Represents a yearly period with a specific year.
+    #[non_exhaustive]
     Year(u32),
     /// Represents a quarterly period with a specific year and quarter (1-4).
+    #[non_exhaustive]
     Quarter(u32, u32),
     /// Represents a monthly period with a specific year and month (1-12).
+    #[non_exhaustive]
     Month(u32, u32),
     /// Represents a daily period with a specific year and day of the year (1-366).
+    #[non_exhaustive]
     Daily(u32, u32),
 }
 
+/// The granularity of a [`DatePeriod`], without its year or index
+///
+/// Kinds are ordered from coarsest to finest, so `Year < Quarter < Month <
+/// Daily`. The string form is the period name (`"YEAR"`, `"QUARTER"`,
+/// `"MONTH"`, `"DAILY"`); parsing also accepts the short names used in period
+/// strings (`"Y"`, `"Q"`, `"M"`, `"D"`) and `"DAY"`, ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// use range_date::range_type::{DatePeriod, PeriodKind};
+///
+/// let kind: PeriodKind = "month".parse().unwrap();
+/// assert_eq!(kind, DatePeriod::month(2024, 3).unwrap().kind());
+/// assert_eq!(kind.to_string(), "MONTH");
+/// assert!(kind.is_finer_than(PeriodKind::Quarter));
+/// assert_eq!(kind.parent(), Some(PeriodKind::Quarter));
+/// assert_eq!(kind.child(), Some(PeriodKind::Daily));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PeriodKind {
+    /// Yearly periods, see [`DatePeriod::Year`].
+    Year,
+    /// Quarterly periods, see [`DatePeriod::Quarter`].
+    Quarter,
+    /// Monthly periods, see [`DatePeriod::Month`].
+    Month,
+    /// Daily periods, see [`DatePeriod::Daily`].
+    Daily,
+}
+
 impl Serialize for DatePeriod {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -43,30 +104,210 @@ impl std::str::FromStr for DatePeriod {
     }
 }
 
+/// Formats periods as `2024Y`, `2024Q1`, `2024M3` or `2024D61`
+///
+/// The alternate form (`{:#}`) writes daily periods as ISO calendar dates
+/// (`2024-03-01`); other kinds are unaffected.
 impl std::fmt::Display for DatePeriod {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             DatePeriod::Year(year) => write!(f, "{}Y", year),
             DatePeriod::Quarter(year, quarter) => write!(f, "{}Q{}", year, quarter),
             DatePeriod::Month(year, month) => write!(f, "{}M{}", year, month),
-            DatePeriod::Daily(year, day) => write!(f, "{}D{}", year, day),
+            DatePeriod::Daily(year, day) => match self.to_date() {
+                // Written by hand: chrono's `%Y` adds a sign to years past 9999
+                Some(date) if f.alternate() => {
+                    write!(f, "{:04}-{:02}-{:02}", year, date.month(), date.day())
+                }
+                _ => write!(f, "{}D{}", year, day),
+            },
+        }
+    }
+}
+
+impl PeriodKind {
+    /// All kinds, from coarsest to finest
+    pub const ALL: [PeriodKind; 4] = [
+        PeriodKind::Year,
+        PeriodKind::Quarter,
+        PeriodKind::Month,
+        PeriodKind::Daily,
+    ];
+
+    /// Get the short name used in period strings
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::PeriodKind;
+    ///
+    /// assert_eq!(PeriodKind::Quarter.short_name(), "Q");
+    /// ```
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            PeriodKind::Year => "Y",
+            PeriodKind::Quarter => "Q",
+            PeriodKind::Month => "M",
+            PeriodKind::Daily => "D",
+        }
+    }
+
+    /// Get the full name of the kind
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::PeriodKind;
+    ///
+    /// assert_eq!(PeriodKind::Daily.name(), "DAILY");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            PeriodKind::Year => "YEAR",
+            PeriodKind::Quarter => "QUARTER",
+            PeriodKind::Month => "MONTH",
+            PeriodKind::Daily => "DAILY",
+        }
+    }
+
+    /// Check whether periods of this kind are strictly shorter than periods
+    /// of `other`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::PeriodKind;
+    ///
+    /// assert!(PeriodKind::Daily.is_finer_than(PeriodKind::Year));
+    /// assert!(!PeriodKind::Month.is_finer_than(PeriodKind::Month));
+    /// ```
+    pub fn is_finer_than(&self, other: PeriodKind) -> bool {
+        *self > other
+    }
+
+    /// Check whether periods of this kind are strictly longer than periods
+    /// of `other`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::PeriodKind;
+    ///
+    /// assert!(PeriodKind::Year.is_coarser_than(PeriodKind::Quarter));
+    /// ```
+    pub fn is_coarser_than(&self, other: PeriodKind) -> bool {
+        *self < other
+    }
+
+    /// Get the next coarser kind, as used by [`DatePeriod::aggregate`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::PeriodKind;
+    ///
+    /// assert_eq!(PeriodKind::Month.parent(), Some(PeriodKind::Quarter));
+    /// assert_eq!(PeriodKind::Year.parent(), None);
+    /// ```
+    pub fn parent(&self) -> Option<PeriodKind> {
+        match self {
+            PeriodKind::Year => None,
+            PeriodKind::Quarter => Some(PeriodKind::Year),
+            PeriodKind::Month => Some(PeriodKind::Quarter),
+            PeriodKind::Daily => Some(PeriodKind::Month),
+        }
+    }
+
+    /// Get the next finer kind, as used by [`DatePeriod::decompose`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::PeriodKind;
+    ///
+    /// assert_eq!(PeriodKind::Quarter.child(), Some(PeriodKind::Month));
+    /// assert_eq!(PeriodKind::Daily.child(), None);
+    /// ```
+    pub fn child(&self) -> Option<PeriodKind> {
+        match self {
+            PeriodKind::Year => Some(PeriodKind::Quarter),
+            PeriodKind::Quarter => Some(PeriodKind::Month),
+            PeriodKind::Month => Some(PeriodKind::Daily),
+            PeriodKind::Daily => None,
         }
     }
 }
 
+impl std::fmt::Display for PeriodKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for PeriodKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "Y" | "YEAR" => Ok(PeriodKind::Year),
+            "Q" | "QUARTER" => Ok(PeriodKind::Quarter),
+            "M" | "MONTH" => Ok(PeriodKind::Month),
+            "D" | "DAY" | "DAILY" => Ok(PeriodKind::Daily),
+            _ => Err(anyhow::anyhow!("Invalid period kind: {}", s)),
+        }
+    }
+}
+
+impl Serialize for PeriodKind {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for PeriodKind {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<PeriodKind, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use std::str::FromStr;
+        let s = String::deserialize(deserializer)?;
+        PeriodKind::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 impl DatePeriod {
-    /// Create a new yearly period
+    /// The earliest year a `DatePeriod` can be navigated to
+    pub const MIN_YEAR: u32 = 0;
+
+    /// The latest year a `DatePeriod` can be navigated to
+    ///
+    /// This is the last complete year [`NaiveDate`] can represent, so the
+    /// first and last day of every period up to this year can be computed.
+    /// Navigation methods never produce periods outside
+    /// [`DatePeriod::MIN_YEAR`]`..=`[`DatePeriod::MAX_YEAR`].
+    pub const MAX_YEAR: u32 = 262_142;
+
+    /// Create a new yearly period with validation
+    /// Year must not exceed [`DatePeriod::MAX_YEAR`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `year` is greater than [`DatePeriod::MAX_YEAR`].
     ///
     /// # Examples
     ///
     /// ```
     /// use range_date::range_type::DatePeriod;
     ///
-    /// let year = DatePeriod::year(2024);
+    /// let year = DatePeriod::year(2024).unwrap();
     /// assert_eq!(year.to_string(), "2024Y");
     /// ```
-    pub fn year(year: u32) -> Self {
-        DatePeriod::Year(year)
+    pub fn year(year: u32) -> anyhow::Result<Self> {
+        Self::check_year(year)?;
+        Ok(DatePeriod::Year(year))
     }
 
     /// Create a new quarterly period with validation
@@ -74,7 +315,8 @@ impl DatePeriod {
     ///
     /// # Errors
     ///
-    /// Returns an error if `quarter` is not in the range `1..=4`.
+    /// Returns an error if `quarter` is not in the range `1..=4` or `year` is
+    /// greater than [`DatePeriod::MAX_YEAR`].
     ///
     /// # Examples
     ///
@@ -85,6 +327,7 @@ impl DatePeriod {
     /// assert_eq!(quarter.to_string(), "2024Q2");
     /// ```
     pub fn quarter(year: u32, quarter: u32) -> anyhow::Result<Self> {
+        Self::check_year(year)?;
         if !(1..=4).contains(&quarter) {
             return Err(anyhow::anyhow!(
                 "Quarter must be between 1 and 4, got: {}",
@@ -99,7 +342,8 @@ impl DatePeriod {
     ///
     /// # Errors
     ///
-    /// Returns an error if `month` is not in the range `1..=12`.
+    /// Returns an error if `month` is not in the range `1..=12` or `year` is
+    /// greater than [`DatePeriod::MAX_YEAR`].
     ///
     /// # Examples
     ///
@@ -110,6 +354,7 @@ impl DatePeriod {
     /// assert_eq!(month.to_string(), "2024M5");
     /// ```
     pub fn month(year: u32, month: u32) -> anyhow::Result<Self> {
+        Self::check_year(year)?;
         if !(1..=12).contains(&month) {
             return Err(anyhow::anyhow!(
                 "Month must be between 1 and 12, got: {}",
@@ -125,7 +370,8 @@ impl DatePeriod {
     /// # Errors
     ///
     /// Returns an error if `day` is `0` or exceeds the number of days in `year`
-    /// (365 for common years, 366 for leap years).
+    /// (365 for common years, 366 for leap years), or if `year` is greater
+    /// than [`DatePeriod::MAX_YEAR`].
     ///
     /// # Examples
     ///
@@ -136,6 +382,7 @@ impl DatePeriod {
     /// assert_eq!(daily.to_string(), "2024D136");
     /// ```
     pub fn daily(year: u32, day: u32) -> anyhow::Result<Self> {
+        Self::check_year(year)?;
         if day == 0 {
             return Err(anyhow::anyhow!("Day must be greater than 0"));
         }
@@ -152,13 +399,52 @@ impl DatePeriod {
         Ok(DatePeriod::Daily(year, day))
     }
 
+    /// Create a new daily period from a calendar date
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `month`/`day` is not a valid date in `year`, or if
+    /// `year` is greater than [`DatePeriod::MAX_YEAR`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let daily = DatePeriod::daily_from_ymd(2024, 3, 1).unwrap();
+    /// assert_eq!(daily, DatePeriod::daily(2024, 61).unwrap());
+    /// assert!(DatePeriod::daily_from_ymd(2023, 2, 29).is_err());
+    /// ```
+    pub fn daily_from_ymd(year: u32, month: u32, day: u32) -> anyhow::Result<Self> {
+        Self::check_year(year)?;
+        let date = NaiveDate::from_ymd_opt(year as i32, month, day).ok_or_else(|| {
+            anyhow::anyhow!("Invalid date: year {}, month {}, day {}", year, month, day)
+        })?;
+        Ok(DatePeriod::Daily(year, date.ordinal()))
+    }
+
+    fn check_year(year: u32) -> anyhow::Result<()> {
+        if year > Self::MAX_YEAR {
+            return Err(anyhow::anyhow!(
+                "Year must not exceed {}, got: {}",
+                Self::MAX_YEAR,
+                year
+            ));
+        }
+        Ok(())
+    }
+
     /// Parse a `DatePeriod` from a string representation like `"2024Q2"`
     /// Format: `YYYYT[#]` where `T` is period type (Y/Q/M/D) and `#` is the index (optional for Y)
+    /// The year is the leading run of digits, so `"999Y"` and `"10000Q1"` are valid.
+    ///
+    /// Daily periods can also be written with their calendar date, either as
+    /// `YYYYM#D#` (`"2024M3D1"`) or as an ISO date (`"2024-03-01"`).
     ///
     /// # Errors
     ///
-    /// Returns an error if the input is shorter than five characters, the year
-    /// or index cannot be parsed, the period type is unknown, or the resulting
+    /// Returns an error if the input does not start with the year's digits, the
+    /// year or index cannot be parsed, the period type is unknown, or the resulting
     /// period fails validation (e.g. invalid quarter/month/day).
     ///
     /// # Examples
@@ -168,31 +454,59 @@ impl DatePeriod {
     ///
     /// let period = DatePeriod::parse("2024Q2").unwrap();
     /// assert_eq!(period.to_string(), "2024Q2");
+    ///
+    /// let day = DatePeriod::parse("2024M3D1").unwrap();
+    /// assert_eq!(day, DatePeriod::parse("2024-03-01").unwrap());
+    /// assert_eq!(day.to_string(), "2024D61");
     /// ```
     pub fn parse(s: &str) -> anyhow::Result<Self> {
         let s = s.trim();
-        if s.len() < 5 {
+        let digits = s.bytes().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 || s.len() <= digits {
             return Err(anyhow::anyhow!("Invalid format, expected YYYYT[#]: {}", s));
         }
 
-        let year: u32 = s[0..4]
+        let year: u32 = s[..digits]
             .parse()
             .map_err(|_| anyhow::anyhow!("Invalid year in: {}", s))?;
-        let period_type = &s[4..5];
+        let rest = &s[digits..];
+        let period_type = rest
+            .get(0..1)
+            .ok_or_else(|| anyhow::anyhow!("Invalid period type in: {}", s))?;
+        let index = &rest[1..];
 
         match period_type {
+            "-" => {
+                let invalid = || anyhow::anyhow!("Invalid date, expected YYYY-MM-DD: {}", s);
+                let (month, day) = index.split_once('-').ok_or_else(invalid)?;
+                if month.len() != 2 || day.len() != 2 {
+                    return Err(invalid());
+                }
+                let month: u32 = month.parse().map_err(|_| invalid())?;
+                let day: u32 = day.parse().map_err(|_| invalid())?;
+                Self::daily_from_ymd(year, month, day)
+            }
             "Y" => {
                 // Year format is just "2024Y" - no index needed
-                if s.len() != 5 {
+                if !index.is_empty() {
                     return Err(anyhow::anyhow!("Year format should be YYYYY: {}", s));
                 }
-                Ok(Self::year(year))
+                Self::year(year)
             }
             "Q" | "M" | "D" => {
-                if s.len() <= 5 {
+                if index.is_empty() {
                     return Err(anyhow::anyhow!("Missing index for {}: {}", period_type, s));
                 }
-                let index: u32 = s[5..]
+                if let ("M", Some((month, day))) = (period_type, index.split_once('D')) {
+                    let month: u32 = month
+                        .parse()
+                        .map_err(|_| anyhow::anyhow!("Invalid month in: {}", s))?;
+                    let day: u32 = day
+                        .parse()
+                        .map_err(|_| anyhow::anyhow!("Invalid day in: {}", s))?;
+                    return Self::daily_from_ymd(year, month, day);
+                }
+                let index: u32 = index
                     .parse()
                     .map_err(|_| anyhow::anyhow!("Invalid index in: {}", s))?;
 
@@ -213,6 +527,10 @@ impl DatePeriod {
 
     /// Convert a `NaiveDate` to a yearly `DatePeriod`
     ///
+    /// # Errors
+    ///
+    /// Returns an error if `date` lies before [`DatePeriod::MIN_YEAR`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -220,15 +538,19 @@ impl DatePeriod {
     /// use chrono::NaiveDate;
     ///
     /// let date = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
-    /// let year = DatePeriod::from_date_as_year(date);
+    /// let year = DatePeriod::from_date_as_year(date).unwrap();
     /// assert_eq!(year.to_string(), "2024Y");
     /// ```
-    pub fn from_date_as_year(date: NaiveDate) -> Self {
-        Self::year(date.year() as u32)
+    pub fn from_date_as_year(date: NaiveDate) -> anyhow::Result<Self> {
+        Ok(DatePeriod::Year(Self::year_of(date)?))
     }
 
     /// Convert a `NaiveDate` to a quarterly `DatePeriod`
     ///
+    /// # Errors
+    ///
+    /// Returns an error if `date` lies before [`DatePeriod::MIN_YEAR`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -236,11 +558,11 @@ impl DatePeriod {
     /// use chrono::NaiveDate;
     ///
     /// let date = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
-    /// let quarter = DatePeriod::from_date_as_quarter(date);
+    /// let quarter = DatePeriod::from_date_as_quarter(date).unwrap();
     /// assert_eq!(quarter.to_string(), "2024Q2");
     /// ```
-    pub fn from_date_as_quarter(date: NaiveDate) -> Self {
-        let year = date.year() as u32;
+    pub fn from_date_as_quarter(date: NaiveDate) -> anyhow::Result<Self> {
+        let year = Self::year_of(date)?;
         let month = date.month();
         let quarter = match month {
             1..=3 => 1,
@@ -249,11 +571,15 @@ impl DatePeriod {
             10..=12 => 4,
             _ => unreachable!(),
         };
-        DatePeriod::Quarter(year, quarter)
+        Ok(DatePeriod::Quarter(year, quarter))
     }
 
     /// Convert a `NaiveDate` to a monthly `DatePeriod`
     ///
+    /// # Errors
+    ///
+    /// Returns an error if `date` lies before [`DatePeriod::MIN_YEAR`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -261,15 +587,19 @@ impl DatePeriod {
     /// use chrono::NaiveDate;
     ///
     /// let date = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
-    /// let month = DatePeriod::from_date_as_month(date);
+    /// let month = DatePeriod::from_date_as_month(date).unwrap();
     /// assert_eq!(month.to_string(), "2024M5");
     /// ```
-    pub fn from_date_as_month(date: NaiveDate) -> Self {
-        DatePeriod::Month(date.year() as u32, date.month())
+    pub fn from_date_as_month(date: NaiveDate) -> anyhow::Result<Self> {
+        Ok(DatePeriod::Month(Self::year_of(date)?, date.month()))
     }
 
     /// Convert a `NaiveDate` to a daily `DatePeriod`
     ///
+    /// # Errors
+    ///
+    /// Returns an error if `date` lies before [`DatePeriod::MIN_YEAR`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -277,11 +607,196 @@ impl DatePeriod {
     /// use chrono::NaiveDate;
     ///
     /// let date = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
-    /// let daily = DatePeriod::from_date_as_daily(date);
+    /// let daily = DatePeriod::from_date_as_daily(date).unwrap();
     /// assert_eq!(daily.to_string(), "2024D136");
     /// ```
-    pub fn from_date_as_daily(date: NaiveDate) -> Self {
-        DatePeriod::Daily(date.year() as u32, date.ordinal())
+    pub fn from_date_as_daily(date: NaiveDate) -> anyhow::Result<Self> {
+        Ok(DatePeriod::Daily(Self::year_of(date)?, date.ordinal()))
+    }
+
+    /// Create the period of `kind` containing `date`
+    ///
+    /// Equivalent to the `from_date_as_*` function for `kind`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `date` lies before [`DatePeriod::MIN_YEAR`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::{DatePeriod, PeriodKind};
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 8, 15).unwrap();
+    /// for kind in PeriodKind::ALL {
+    ///     assert!(DatePeriod::from_date(date, kind).unwrap().contains_date(date));
+    /// }
+    /// assert_eq!(DatePeriod::from_date(date, PeriodKind::Quarter).unwrap().to_string(), "2024Q3");
+    /// ```
+    pub fn from_date(date: NaiveDate, kind: PeriodKind) -> anyhow::Result<Self> {
+        match kind {
+            PeriodKind::Year => Self::from_date_as_year(date),
+            PeriodKind::Quarter => Self::from_date_as_quarter(date),
+            PeriodKind::Month => Self::from_date_as_month(date),
+            PeriodKind::Daily => Self::from_date_as_daily(date),
+        }
+    }
+
+    /// The year of `date`, if it is within the supported range
+    fn year_of(date: NaiveDate) -> anyhow::Result<u32> {
+        // `NaiveDate` never exceeds `MAX_YEAR`, only the lower bound can fail
+        u32::try_from(date.year())
+            .map_err(|_| anyhow::anyhow!("Date {} is before year {}", date, Self::MIN_YEAR))
+    }
+
+    /// Convert an instant to the yearly `DatePeriod` it falls in, as observed
+    /// in the time zone `tz`
+    ///
+    /// `tz` can be any [`chrono::TimeZone`], e.g. [`chrono::Utc`],
+    /// [`chrono::FixedOffset`] or a `chrono_tz::Tz`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local date lies before [`DatePeriod::MIN_YEAR`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 12, 31, 20, 0, 0).unwrap();
+    /// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    /// assert_eq!(DatePeriod::from_datetime_as_year(&dt, &tokyo).unwrap().to_string(), "2025Y");
+    /// ```
+    pub fn from_datetime_as_year<T: TimeZone, Tz: TimeZone>(
+        dt: &DateTime<T>,
+        tz: &Tz,
+    ) -> anyhow::Result<Self> {
+        Self::from_date_as_year(dt.with_timezone(tz).date_naive())
+    }
+
+    /// Convert an instant to the quarterly `DatePeriod` it falls in, as
+    /// observed in the time zone `tz`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 4, 1, 2, 0, 0).unwrap();
+    /// let new_york = FixedOffset::west_opt(4 * 3600).unwrap();
+    /// assert_eq!(DatePeriod::from_datetime_as_quarter(&dt, &new_york).unwrap().to_string(), "2024Q1");
+    /// ```
+    pub fn from_datetime_as_quarter<T: TimeZone, Tz: TimeZone>(
+        dt: &DateTime<T>,
+        tz: &Tz,
+    ) -> anyhow::Result<Self> {
+        Self::from_date_as_quarter(dt.with_timezone(tz).date_naive())
+    }
+
+    /// Convert an instant to the monthly `DatePeriod` it falls in, as observed
+    /// in the time zone `tz`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 5, 31, 23, 30, 0).unwrap();
+    /// let berlin = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// assert_eq!(DatePeriod::from_datetime_as_month(&dt, &berlin).unwrap().to_string(), "2024M6");
+    /// ```
+    pub fn from_datetime_as_month<T: TimeZone, Tz: TimeZone>(
+        dt: &DateTime<T>,
+        tz: &Tz,
+    ) -> anyhow::Result<Self> {
+        Self::from_date_as_month(dt.with_timezone(tz).date_naive())
+    }
+
+    /// Convert an instant to the daily `DatePeriod` it falls in, as observed
+    /// in the time zone `tz`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 5, 15, 12, 0, 0).unwrap();
+    /// assert_eq!(DatePeriod::from_datetime_as_daily(&dt, &Utc).unwrap().to_string(), "2024D136");
+    /// ```
+    pub fn from_datetime_as_daily<T: TimeZone, Tz: TimeZone>(
+        dt: &DateTime<T>,
+        tz: &Tz,
+    ) -> anyhow::Result<Self> {
+        Self::from_date_as_daily(dt.with_timezone(tz).date_naive())
+    }
+
+    /// Generate all periods of `kind` between two dates (inclusive)
+    /// Returns an empty vector if start > end
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `start` lies before [`DatePeriod::MIN_YEAR`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::{DatePeriod, PeriodKind};
+    /// use chrono::NaiveDate;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 4, 30).unwrap();
+    /// let months = DatePeriod::between(start, end, PeriodKind::Month).unwrap();
+    /// assert_eq!(months.len(), 3);
+    /// assert_eq!(months[0].to_string(), "2024M2");
+    /// ```
+    pub fn between(
+        start: NaiveDate,
+        end: NaiveDate,
+        kind: PeriodKind,
+    ) -> anyhow::Result<Vec<DatePeriod>> {
+        if start > end {
+            return Ok(vec![]);
+        }
+        Self::between_periods(Self::from_date(start, kind)?, Self::from_date(end, kind)?)
+    }
+
+    /// Generate all periods from `start` to `end` (inclusive)
+    /// Returns an empty vector if start > end
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `start` and `end` are of different kinds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let start = DatePeriod::quarter(2023, 3).unwrap();
+    /// let end = DatePeriod::quarter(2024, 2).unwrap();
+    /// let quarters = DatePeriod::between_periods(start, end).unwrap();
+    /// assert_eq!(quarters.len(), 4);
+    /// assert_eq!(quarters[1].to_string(), "2023Q4");
+    ///
+    /// assert!(DatePeriod::between_periods(start, DatePeriod::year(2024).unwrap()).is_err());
+    /// ```
+    pub fn between_periods(start: DatePeriod, end: DatePeriod) -> anyhow::Result<Vec<DatePeriod>> {
+        let distance = start.distance_to(&end)?;
+        if distance < 0 {
+            return Ok(vec![]);
+        }
+        Ok(ExpandTo {
+            kind: start.kind(),
+            next: start.ordinal(),
+            end: end.ordinal() + 1,
+        }
+        .collect())
     }
 
     /// Generate all yearly periods between two dates (inclusive)
@@ -289,9 +804,7 @@ impl DatePeriod {
     ///
     /// # Errors
     ///
-    /// This function currently returns `Ok` in all cases; the `Result` return
-    /// type is retained for API symmetry with the other `between_date_as_*`
-    /// helpers which may propagate errors from period arithmetic.
+    /// Returns an error if `start` lies before [`DatePeriod::MIN_YEAR`].
     ///
     /// # Examples
     ///
@@ -309,12 +822,7 @@ impl DatePeriod {
         start: NaiveDate,
         end: NaiveDate,
     ) -> anyhow::Result<Vec<DatePeriod>> {
-        if start > end {
-            return Ok(vec![]);
-        }
-        let start_year = start.year() as u32;
-        let end_year = end.year() as u32;
-        Ok((start_year..=end_year).map(DatePeriod::year).collect())
+        Self::between(start, end, PeriodKind::Year)
     }
 
     /// Generate all quarterly periods between two dates (inclusive)
@@ -322,8 +830,7 @@ impl DatePeriod {
     ///
     /// # Errors
     ///
-    /// Returns an error if advancing the quarterly period via [`DatePeriod::succ`]
-    /// fails while iterating from `start` to `end`.
+    /// Returns an error if `start` lies before [`DatePeriod::MIN_YEAR`].
     ///
     /// # Examples
     ///
@@ -341,17 +848,7 @@ impl DatePeriod {
         start: NaiveDate,
         end: NaiveDate,
     ) -> anyhow::Result<Vec<DatePeriod>> {
-        if start > end {
-            return Ok(vec![]);
-        }
-        let mut result = vec![];
-        let mut current = DatePeriod::from_date_as_quarter(start);
-        let end_quarter = DatePeriod::from_date_as_quarter(end);
-        while current <= end_quarter {
-            result.push(current.clone());
-            current = current.succ()?;
-        }
-        Ok(result)
+        Self::between(start, end, PeriodKind::Quarter)
     }
 
     /// Generate all monthly periods between two dates (inclusive)
@@ -359,8 +856,7 @@ impl DatePeriod {
     ///
     /// # Errors
     ///
-    /// Returns an error if advancing the monthly period via [`DatePeriod::succ`]
-    /// fails while iterating from `start` to `end`.
+    /// Returns an error if `start` lies before [`DatePeriod::MIN_YEAR`].
     ///
     /// # Examples
     ///
@@ -378,17 +874,7 @@ impl DatePeriod {
         start: NaiveDate,
         end: NaiveDate,
     ) -> anyhow::Result<Vec<DatePeriod>> {
-        if start > end {
-            return Ok(vec![]);
-        }
-        let mut result = vec![];
-        let mut current = DatePeriod::from_date_as_month(start);
-        let end_month = DatePeriod::from_date_as_month(end);
-        while current <= end_month {
-            result.push(current.clone());
-            current = current.succ()?;
-        }
-        Ok(result)
+        Self::between(start, end, PeriodKind::Month)
     }
 
     /// Generate all daily periods between two dates (inclusive)
@@ -396,8 +882,7 @@ impl DatePeriod {
     ///
     /// # Errors
     ///
-    /// Returns an error if advancing the daily period via [`DatePeriod::succ`]
-    /// fails while iterating from `start` to `end`.
+    /// Returns an error if `start` lies before [`DatePeriod::MIN_YEAR`].
     ///
     /// # Examples
     ///
@@ -415,17 +900,7 @@ impl DatePeriod {
         start: NaiveDate,
         end: NaiveDate,
     ) -> anyhow::Result<Vec<DatePeriod>> {
-        if start > end {
-            return Ok(vec![]);
-        }
-        let mut result = vec![];
-        let mut current = DatePeriod::from_date_as_daily(start);
-        let end_daily = DatePeriod::from_date_as_daily(end);
-        while current <= end_daily {
-            result.push(current.clone());
-            current = current.succ()?;
-        }
-        Ok(result)
+        Self::between(start, end, PeriodKind::Daily)
     }
 
     /// Get the first day of this period
@@ -491,28 +966,24 @@ impl DatePeriod {
         match self {
             DatePeriod::Year(year) => NaiveDate::from_ymd_opt(*year as i32, 12, 31)
                 .ok_or_else(|| anyhow::anyhow!("Invalid year for last day calculation: {}", year)),
-            DatePeriod::Quarter(_, _) => {
-                let first_day = self.get_first_day()?;
-                let added_months =
-                    first_day
-                        .checked_add_months(Months::new(3))
-                        .ok_or_else(|| {
-                            anyhow::anyhow!("Failed to add 3 months to quarter start date")
-                        })?;
-                let last_day = added_months.pred_opt().ok_or_else(|| {
-                    anyhow::anyhow!("Failed to get predecessor date for quarter end")
-                })?;
-                Ok(last_day)
+            DatePeriod::Quarter(year, quarter) => {
+                DatePeriod::Month(*year, quarter * 3).get_last_day()
             }
-            DatePeriod::Month(_, _) => {
-                let first_day = self.get_first_day()?;
-                let added_months = first_day
-                    .checked_add_months(Months::new(1))
-                    .ok_or_else(|| anyhow::anyhow!("Failed to add 1 month to month start date"))?;
-                let last_day = added_months.pred_opt().ok_or_else(|| {
-                    anyhow::anyhow!("Failed to get predecessor date for month end")
-                })?;
-                Ok(last_day)
+            DatePeriod::Month(year, month) => {
+                // Step back from the first day of the next month; December ends
+                // on the 31st, which also avoids overflowing past the last year.
+                if *month == 12 {
+                    return NaiveDate::from_ymd_opt(*year as i32, 12, 31).ok_or_else(|| {
+                        anyhow::anyhow!("Invalid month date: year {}, month {}", year, month)
+                    });
+                }
+                let next_month =
+                    NaiveDate::from_ymd_opt(*year as i32, month + 1, 1).ok_or_else(|| {
+                        anyhow::anyhow!("Invalid month date: year {}, month {}", year, month)
+                    })?;
+                next_month
+                    .pred_opt()
+                    .ok_or_else(|| anyhow::anyhow!("Failed to get predecessor date for month end"))
             }
             DatePeriod::Daily(_, _) => self.get_first_day(), // Same as first day for daily period
         }
@@ -541,15 +1012,153 @@ impl DatePeriod {
         }
     }
 
-    /// Get the year component
+    /// Get the `n`-th (from 1) occurrence of `weekday` within the period
+    ///
+    /// Returns `None` if `n` is 0, the period has fewer than `n` such days,
+    /// or its boundaries cannot be calculated.
     ///
     /// # Examples
     ///
     /// ```
     /// use range_date::range_type::DatePeriod;
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// // Third Friday of March 2024
+    /// let month = DatePeriod::month(2024, 3).unwrap();
+    /// assert_eq!(
+    ///     month.nth_weekday(Weekday::Fri, 3),
+    ///     NaiveDate::from_ymd_opt(2024, 3, 15)
+    /// );
+    /// assert_eq!(month.nth_weekday(Weekday::Fri, 5), NaiveDate::from_ymd_opt(2024, 3, 29));
+    /// assert_eq!(month.nth_weekday(Weekday::Fri, 6), None);
+    /// ```
+    pub fn nth_weekday(&self, weekday: Weekday, n: u32) -> Option<NaiveDate> {
+        self.weekdays(weekday).nth(n.checked_sub(1)? as usize)
+    }
+
+    /// Get the last occurrence of `weekday` within the period
+    ///
+    /// Returns `None` if `weekday` does not occur in the period, which can only
+    /// happen for daily periods as every other kind spans at least a week, or
+    /// if its boundaries cannot be calculated.
+    ///
+    /// # Examples
     ///
-    /// let period = DatePeriod::month(2024, 2).unwrap();
-    /// assert_eq!(period.get_year(), 2024);
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// // Last Monday of the second quarter of 2024
+    /// let quarter = DatePeriod::quarter(2024, 2).unwrap();
+    /// assert_eq!(
+    ///     quarter.last_weekday(Weekday::Mon),
+    ///     NaiveDate::from_ymd_opt(2024, 6, 24)
+    /// );
+    /// // A single day only contains its own weekday
+    /// let day = DatePeriod::daily_from_ymd(2024, 6, 24).unwrap();
+    /// assert_eq!(day.last_weekday(Weekday::Tue), None);
+    /// ```
+    pub fn last_weekday(&self, weekday: Weekday) -> Option<NaiveDate> {
+        self.weekdays(weekday).next_back()
+    }
+
+    /// Iterate over all days of the period falling on `weekday`, in order
+    ///
+    /// The iterator is empty if the period's boundaries cannot be calculated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    /// use chrono::Weekday;
+    ///
+    /// let month = DatePeriod::month(2024, 2).unwrap();
+    /// let thursdays: Vec<_> = month.weekdays(Weekday::Thu).map(|d| d.to_string()).collect();
+    /// assert_eq!(thursdays, ["2024-02-01", "2024-02-08", "2024-02-15", "2024-02-22", "2024-02-29"]);
+    /// ```
+    pub fn weekdays(&self, weekday: Weekday) -> impl DoubleEndedIterator<Item = NaiveDate> {
+        let (first_day, offsets) = match (self.get_first_day(), self.get_last_day()) {
+            (Ok(first_day), Ok(last_day)) => {
+                let skip = (weekday.num_days_from_monday() + 7
+                    - first_day.weekday().num_days_from_monday())
+                    % 7;
+                let days = (last_day - first_day).num_days() as u32 + 1;
+                (first_day, skip.min(days)..days)
+            }
+            _ => (NaiveDate::MIN, 0..0),
+        };
+        offsets
+            .step_by(7)
+            .map(move |offset| first_day + Duration::days(offset as i64))
+    }
+
+    /// Get the instant at which this period starts in the time zone `tz`
+    ///
+    /// Together with [`DatePeriod::end_instant`] this describes the period as
+    /// the half-open interval `[start, end)`. The start is the first instant of
+    /// [`DatePeriod::get_first_day`] in `tz`: if local midnight is skipped by
+    /// a DST transition the period starts when the gap ends, and if midnight
+    /// occurs twice the earlier instant is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the first day cannot be computed or has no
+    /// representable start in `tz`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(8 * 3600).unwrap();
+    /// let q1 = DatePeriod::quarter(2024, 1).unwrap();
+    /// let start = q1.start_instant(&tz).unwrap();
+    /// assert_eq!(start, tz.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap());
+    /// ```
+    pub fn start_instant<Tz: TimeZone>(&self, tz: &Tz) -> anyhow::Result<DateTime<Tz>> {
+        local_day_start(self.get_first_day()?, tz)
+    }
+
+    /// Get the instant at which this period ends (exclusive) in the time
+    /// zone `tz`
+    ///
+    /// This is the start of the day after [`DatePeriod::get_last_day`], i.e.
+    /// the [`DatePeriod::start_instant`] of the following period, so adjacent
+    /// periods never overlap or leave gaps even across DST transitions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the last day cannot be computed, has no successor
+    /// date, or the following day has no representable start in `tz`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let feb = DatePeriod::month(2024, 2).unwrap();
+    /// let end = feb.end_instant(&Utc).unwrap();
+    /// assert_eq!(end, Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap());
+    /// ```
+    pub fn end_instant<Tz: TimeZone>(&self, tz: &Tz) -> anyhow::Result<DateTime<Tz>> {
+        let next_day = self
+            .get_last_day()?
+            .succ_opt()
+            .ok_or_else(|| anyhow::anyhow!("No day after the end of {}", self))?;
+        local_day_start(next_day, tz)
+    }
+
+    /// Get the year component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let period = DatePeriod::month(2024, 2).unwrap();
+    /// assert_eq!(period.get_year(), 2024);
     /// ```
     pub fn get_year(&self) -> u32 {
         match self {
@@ -567,7 +1176,7 @@ impl DatePeriod {
     /// ```
     /// use range_date::range_type::DatePeriod;
     ///
-    /// let year_period = DatePeriod::year(2024);
+    /// let year_period = DatePeriod::year(2024).unwrap();
     /// assert_eq!(year_period.value(), 2024);
     ///
     /// let month_period = DatePeriod::month(2024, 2).unwrap();
@@ -582,6 +1191,64 @@ impl DatePeriod {
         }
     }
 
+    /// Get the month (1-12) of a monthly or daily period
+    ///
+    /// Returns `None` for yearly and quarterly periods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// assert_eq!(DatePeriod::daily(2024, 61).unwrap().get_month(), Some(3));
+    /// assert_eq!(DatePeriod::month(2024, 7).unwrap().get_month(), Some(7));
+    /// assert_eq!(DatePeriod::quarter(2024, 1).unwrap().get_month(), None);
+    /// ```
+    pub fn get_month(&self) -> Option<u32> {
+        match self {
+            DatePeriod::Month(_, month) => Some(*month),
+            DatePeriod::Daily(_, _) => self.to_date().map(|date| date.month()),
+            _ => None,
+        }
+    }
+
+    /// Get the day of the month (1-31) of a daily period
+    ///
+    /// Returns `None` for all other kinds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// assert_eq!(DatePeriod::daily(2024, 61).unwrap().get_day_of_month(), Some(1));
+    /// assert_eq!(DatePeriod::month(2024, 3).unwrap().get_day_of_month(), None);
+    /// ```
+    pub fn get_day_of_month(&self) -> Option<u32> {
+        self.to_date().map(|date| date.day())
+    }
+
+    /// Get the calendar date of a daily period
+    ///
+    /// Returns `None` for all other kinds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    /// use chrono::NaiveDate;
+    ///
+    /// let day = DatePeriod::daily(2024, 60).unwrap();
+    /// assert_eq!(day.to_date(), NaiveDate::from_ymd_opt(2024, 2, 29));
+    /// assert_eq!(format!("{:#}", day), "2024-02-29");
+    /// ```
+    pub fn to_date(&self) -> Option<NaiveDate> {
+        match self {
+            DatePeriod::Daily(year, day) => NaiveDate::from_yo_opt(*year as i32, *day),
+            _ => None,
+        }
+    }
+
     /// Get the short name of the period type
     ///
     /// # Examples
@@ -589,19 +1256,14 @@ impl DatePeriod {
     /// ```
     /// use range_date::range_type::DatePeriod;
     ///
-    /// let year_period = DatePeriod::year(2024);
+    /// let year_period = DatePeriod::year(2024).unwrap();
     /// assert_eq!(year_period.short_name(), "Y");
     ///
     /// let month_period = DatePeriod::month(2024, 2).unwrap();
     /// assert_eq!(month_period.short_name(), "M");
     /// ```
     pub fn short_name(&self) -> &'static str {
-        match self {
-            DatePeriod::Year(_) => "Y",
-            DatePeriod::Quarter(_, _) => "Q",
-            DatePeriod::Month(_, _) => "M",
-            DatePeriod::Daily(_, _) => "D",
-        }
+        self.kind().short_name()
     }
 
     /// Get the full name of the period type
@@ -611,18 +1273,98 @@ impl DatePeriod {
     /// ```
     /// use range_date::range_type::DatePeriod;
     ///
-    /// let year_period = DatePeriod::year(2024);
+    /// let year_period = DatePeriod::year(2024).unwrap();
     /// assert_eq!(year_period.period_name(), "YEAR");
     ///
     /// let month_period = DatePeriod::month(2024, 2).unwrap();
     /// assert_eq!(month_period.period_name(), "MONTH");
     /// ```
     pub fn period_name(&self) -> &'static str {
+        self.kind().name()
+    }
+
+    /// Get the granularity of this period
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::{DatePeriod, PeriodKind};
+    ///
+    /// let month_period = DatePeriod::month(2024, 2).unwrap();
+    /// assert_eq!(month_period.kind(), PeriodKind::Month);
+    /// ```
+    pub fn kind(&self) -> PeriodKind {
         match self {
-            DatePeriod::Year(_) => "YEAR",
-            DatePeriod::Quarter(_, _) => "QUARTER",
-            DatePeriod::Month(_, _) => "MONTH",
-            DatePeriod::Daily(_, _) => "DAILY",
+            DatePeriod::Year(_) => PeriodKind::Year,
+            DatePeriod::Quarter(_, _) => PeriodKind::Quarter,
+            DatePeriod::Month(_, _) => PeriodKind::Month,
+            DatePeriod::Daily(_, _) => PeriodKind::Daily,
+        }
+    }
+
+    /// Encode this period as a `u64`
+    ///
+    /// The layout, from the most significant bit, is:
+    ///
+    /// | Bits      | Content                                                   |
+    /// | --------- | --------------------------------------------------------- |
+    /// | `56..64`  | kind code: `0` year, `1` quarter, `2` month, `3` daily    |
+    /// | `24..56`  | year                                                      |
+    /// | `0..24`   | quarter (1-4), month (1-12), day of year (1-366), `0` for a year |
+    ///
+    /// Comparing encoded values orders periods exactly like [`Ord`] on
+    /// `DatePeriod`: by kind first, then chronologically within a kind. The
+    /// encoding is therefore usable as a sortable database key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let period = DatePeriod::month(2024, 3).unwrap();
+    /// assert_eq!(period.to_bits(), 0x0200_0007_E800_0003);
+    /// assert_eq!(DatePeriod::from_bits(period.to_bits()).unwrap(), period);
+    /// ```
+    pub fn to_bits(&self) -> u64 {
+        let (code, year, value) = match *self {
+            DatePeriod::Year(year) => (0, year, 0),
+            DatePeriod::Quarter(year, quarter) => (1, year, quarter),
+            DatePeriod::Month(year, month) => (2, year, month),
+            DatePeriod::Daily(year, day) => (3, year, day),
+        };
+        (code << 56) | ((year as u64) << 24) | value as u64
+    }
+
+    /// Decode a period produced by [`DatePeriod::to_bits`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the kind code is unknown or if the year and value
+    /// do not form a valid period (the same validation as the constructors).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let day = DatePeriod::from_bits((3 << 56) | (2024 << 24) | 60).unwrap();
+    /// assert_eq!(day.to_string(), "2024D60");
+    ///
+    /// assert!(DatePeriod::from_bits((2 << 56) | (2024 << 24) | 13).is_err());
+    /// ```
+    pub fn from_bits(bits: u64) -> anyhow::Result<Self> {
+        let year = (bits >> 24) as u32;
+        let value = (bits & 0xFF_FFFF) as u32;
+        match bits >> 56 {
+            0 if value == 0 => Self::year(year),
+            0 => Err(anyhow::anyhow!(
+                "Year encoding must have no value bits, got: {:#x}",
+                bits
+            )),
+            1 => Self::quarter(year, value),
+            2 => Self::month(year, value),
+            3 => Self::daily(year, value),
+            code => Err(anyhow::anyhow!("Unknown period kind code: {}", code)),
         }
     }
 
@@ -630,8 +1372,8 @@ impl DatePeriod {
     ///
     /// # Errors
     ///
-    /// This function currently never fails in practice; the `Result` return
-    /// type is kept for API symmetry with [`DatePeriod::pred`].
+    /// Returns an error if the successor would lie after
+    /// [`DatePeriod::MAX_YEAR`].
     ///
     /// # Examples
     ///
@@ -641,33 +1383,11 @@ impl DatePeriod {
     /// let period = DatePeriod::month(2024, 2).unwrap();
     /// let next_period = period.succ().unwrap();
     /// assert_eq!(next_period.to_string(), "2024M3");
+    ///
+    /// assert!(DatePeriod::year(DatePeriod::MAX_YEAR).unwrap().succ().is_err());
     /// ```
     pub fn succ(&self) -> anyhow::Result<DatePeriod> {
-        Ok(match self {
-            DatePeriod::Year(year) => DatePeriod::Year(year + 1),
-            DatePeriod::Quarter(year, quarter) => {
-                if *quarter < 4 {
-                    DatePeriod::Quarter(*year, quarter + 1)
-                } else {
-                    DatePeriod::Quarter(year + 1, 1)
-                }
-            }
-            DatePeriod::Month(year, month) => {
-                if *month < 12 {
-                    DatePeriod::Month(*year, month + 1)
-                } else {
-                    DatePeriod::Month(year + 1, 1)
-                }
-            }
-            DatePeriod::Daily(year, day) => {
-                let max_days = if leap_year(*year as i32) { 366 } else { 365 };
-                if *day < max_days {
-                    DatePeriod::Daily(*year, day + 1)
-                } else {
-                    DatePeriod::Daily(year + 1, 1)
-                }
-            }
-        })
+        self.succ_n(1)
     }
 
     /// Get the predecessor (previous) period
@@ -687,52 +1407,15 @@ impl DatePeriod {
     /// assert_eq!(prev_period.to_string(), "2024M1");
     /// ```
     pub fn pred(&self) -> anyhow::Result<DatePeriod> {
-        Ok(match self {
-            DatePeriod::Year(year) => {
-                if *year > 0 {
-                    DatePeriod::Year(year - 1)
-                } else {
-                    anyhow::bail!("No predecessor for year 0");
-                }
-            }
-            DatePeriod::Quarter(year, quarter) => {
-                if *quarter > 1 {
-                    DatePeriod::Quarter(*year, quarter - 1)
-                } else if *year > 0 {
-                    DatePeriod::Quarter(year - 1, 4)
-                } else {
-                    anyhow::bail!("No predecessor for quarter 1 of year 0");
-                }
-            }
-            DatePeriod::Month(year, month) => {
-                if *month > 1 {
-                    DatePeriod::Month(*year, month - 1)
-                } else if *year > 0 {
-                    DatePeriod::Month(year - 1, 12)
-                } else {
-                    anyhow::bail!("No predecessor for month 1 of year 0");
-                }
-            }
-            DatePeriod::Daily(year, day) => {
-                if *day > 1 {
-                    DatePeriod::Daily(*year, day - 1)
-                } else if *year > 0 {
-                    let prev_year = year - 1;
-                    let max_days_prev = if leap_year(prev_year as i32) {
-                        366
-                    } else {
-                        365
-                    };
-                    DatePeriod::Daily(prev_year, max_days_prev)
-                } else {
-                    anyhow::bail!("No predecessor for day 1 of year 0");
-                }
-            }
-        })
+        self.pred_n(1)
     }
 
     /// Decompose this period into its direct sub-periods
     ///
+    /// A year decomposes into quarters, a quarter into months and a month
+    /// into its days. Use [`DatePeriod::expand_to`] to go down several levels
+    /// at once.
+    ///
     /// # Examples
     ///
     /// ```
@@ -743,39 +1426,96 @@ impl DatePeriod {
     /// assert_eq!(months.len(), 3);
     /// assert_eq!(months[0].to_string(), "2024M1");
     /// assert_eq!(months[2].to_string(), "2024M3");
+    ///
+    /// let days = DatePeriod::month(2024, 3).unwrap().decompose();
+    /// assert_eq!(days[0].to_string(), "2024D61");
     /// ```
     pub fn decompose(&self) -> Vec<DatePeriod> {
-        match self {
-            DatePeriod::Year(year) => (1..=4)
-                .map(|q| match DatePeriod::quarter(*year, q) {
-                    Ok(period) => period,
-                    Err(_) => unreachable!("quarter should always succeed for valid q"),
-                })
-                .collect(),
-            DatePeriod::Quarter(year, quarter) => {
-                let start_month = (quarter - 1) * 3 + 1;
-                (0..3)
-                    .map(|i| match DatePeriod::month(*year, start_month + i) {
-                        Ok(period) => period,
-                        Err(_) => unreachable!("month should always succeed for valid month"),
-                    })
-                    .collect()
-            }
-            DatePeriod::Month(year, month) => {
-                let first_day = match NaiveDate::from_ymd_opt(*year as i32, *month, 1) {
-                    Some(date) => date,
-                    None => unreachable!("from_ymd_opt should succeed for valid year and month"),
-                };
-                let last_day = first_day + Months::new(1) - Duration::days(1);
-                (1..=last_day.day())
-                    .map(|d| match DatePeriod::daily(*year, d) {
-                        Ok(period) => period,
-                        Err(_) => unreachable!("daily should always succeed for valid day"),
-                    })
-                    .collect()
-            }
-            DatePeriod::Daily(_, _) => vec![],
+        self.kind()
+            .child()
+            .and_then(|child| self.expand_to(child).ok())
+            .map(Iterator::collect)
+            .unwrap_or_default()
+    }
+
+    /// Iterate over the periods of a finer (or the same) `kind` that make up
+    /// this period
+    ///
+    /// Periods are produced lazily in chronological order. A sub-period
+    /// belongs to the period containing its first day, so expanding adjacent
+    /// periods never yields the same sub-period twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `kind` is coarser than this period's kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::{DatePeriod, PeriodKind};
+    ///
+    /// let year = DatePeriod::year(2024).unwrap();
+    /// let mut days = year.expand_to(PeriodKind::Daily).unwrap();
+    /// assert_eq!(days.len(), 366);
+    /// assert_eq!(days.next().unwrap().to_string(), "2024D1");
+    /// assert_eq!(days.next_back().unwrap().to_string(), "2024D366");
+    ///
+    /// let months: Vec<_> = year.expand_to(PeriodKind::Month).unwrap().collect();
+    /// assert_eq!(months.len(), 12);
+    ///
+    /// assert!(DatePeriod::month(2024, 1).unwrap().expand_to(PeriodKind::Year).is_err());
+    /// ```
+    pub fn expand_to(&self, kind: PeriodKind) -> anyhow::Result<ExpandTo> {
+        if kind.is_coarser_than(self.kind()) {
+            return Err(anyhow::anyhow!(
+                "Cannot expand {} to the coarser kind {}",
+                self,
+                kind
+            ));
+        }
+        let first_day = self.get_first_day()?;
+        let mut first = Self::from_date(first_day, kind)?;
+        if first.get_first_day()? < first_day {
+            first = first.succ()?;
         }
+        let last = Self::from_date(self.get_last_day()?, kind)?;
+        Ok(ExpandTo {
+            kind,
+            next: first.ordinal(),
+            end: last.ordinal() + 1,
+        })
+    }
+
+    /// Convert this period to the coarser (or same) `kind` period containing it
+    ///
+    /// Unlike [`DatePeriod::aggregate`], this can skip levels, e.g. from a day
+    /// straight to its year.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `kind` is finer than this period's kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::{DatePeriod, PeriodKind};
+    ///
+    /// let day = DatePeriod::daily(2024, 100).unwrap();
+    /// assert_eq!(day.to_kind(PeriodKind::Year).unwrap().to_string(), "2024Y");
+    /// assert_eq!(day.to_kind(PeriodKind::Quarter).unwrap().to_string(), "2024Q2");
+    /// assert_eq!(day.to_kind(PeriodKind::Daily).unwrap(), day);
+    ///
+    /// assert!(DatePeriod::year(2024).unwrap().to_kind(PeriodKind::Month).is_err());
+    /// ```
+    pub fn to_kind(&self, kind: PeriodKind) -> anyhow::Result<DatePeriod> {
+        if kind.is_finer_than(self.kind()) {
+            return Err(anyhow::anyhow!(
+                "Cannot convert {} to the finer kind {}",
+                self,
+                kind
+            ));
+        }
+        Self::from_date(self.get_first_day()?, kind)
     }
 
     /// Aggregate this period to its direct parent period
@@ -791,16 +1531,16 @@ impl DatePeriod {
     ///
     /// let quarter = DatePeriod::quarter(2024, 2).unwrap();
     /// let year = quarter.aggregate();
-    /// assert_eq!(year, DatePeriod::year(2024));
+    /// assert_eq!(year, DatePeriod::year(2024).unwrap());
     ///
-    /// let year_period = DatePeriod::year(2024);
+    /// let year_period = DatePeriod::year(2024).unwrap();
     /// let parent = year_period.aggregate();
     /// assert_eq!(parent, year_period); // Year has no parent, remains the same
     /// ```
     pub fn aggregate(&self) -> DatePeriod {
         match self {
-            DatePeriod::Year(_) => self.clone(),
-            DatePeriod::Quarter(year, _) => DatePeriod::year(*year),
+            DatePeriod::Year(_) => *self,
+            DatePeriod::Quarter(year, _) => DatePeriod::Year(*year),
             DatePeriod::Month(year, month) => {
                 let quarter = ((month - 1) / 3) + 1;
                 match DatePeriod::quarter(*year, quarter) {
@@ -825,11 +1565,13 @@ impl DatePeriod {
     ///
     /// Returns the period that is n steps ahead of the current period.
     /// If n is 0, returns the current period.
+    /// Daily periods are shifted in constant time through their day count
+    /// since the common era, whatever the size of n.
     ///
     /// # Errors
     ///
-    /// This function currently never fails in practice; the `Result` return
-    /// type is kept for API symmetry with [`DatePeriod::pred_n`].
+    /// Returns an error for daily periods whose start or target day cannot be
+    /// represented as a [`NaiveDate`].
     ///
     /// # Examples
     ///
@@ -842,43 +1584,15 @@ impl DatePeriod {
     /// ```
     pub fn succ_n(&self, n: u32) -> anyhow::Result<DatePeriod> {
         if n == 0 {
-            return Ok(self.clone());
+            return Ok(*self);
         }
-        Ok(match self {
-            DatePeriod::Year(year) => DatePeriod::Year(year + n),
-            DatePeriod::Quarter(year, quarter) => {
-                let total_quarters = (*year as u64 * 4) + (*quarter as u64 - 1) + n as u64;
-                let new_year = (total_quarters / 4) as u32;
-                let new_quarter = ((total_quarters % 4) + 1) as u32;
-                DatePeriod::Quarter(new_year, new_quarter)
-            }
-            DatePeriod::Month(year, month) => {
-                let total_months = (*year as u64 * 12) + (*month as u64 - 1) + n as u64;
-                let new_year = (total_months / 12) as u32;
-                let new_month = ((total_months % 12) + 1) as u32;
-                DatePeriod::Month(new_year, new_month)
-            }
-            DatePeriod::Daily(year, day) => {
-                let mut current_year = *year;
-                let mut current_day = *day;
-                let mut remaining = n;
-                while remaining > 0 {
-                    let max_days = if leap_year(current_year as i32) {
-                        366
-                    } else {
-                        365
-                    };
-                    if current_day + remaining <= max_days {
-                        current_day += remaining;
-                        break;
-                    } else {
-                        remaining -= max_days - current_day + 1;
-                        current_year += 1;
-                        current_day = 1;
-                    }
-                }
-                DatePeriod::Daily(current_year, current_day)
-            }
+        self.checked_succ_n(n).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Cannot go forward {} periods from {} past year {}",
+                n,
+                self,
+                Self::MAX_YEAR
+            )
         })
     }
 
@@ -887,11 +1601,13 @@ impl DatePeriod {
     /// Returns the period that is n steps back from the current period.
     /// If n is 0, returns the current period.
     /// Returns an error if going back would result in an invalid year (less than 0).
+    /// Daily periods are shifted in constant time, as in [`DatePeriod::succ_n`].
     ///
     /// # Errors
     ///
     /// Returns an error if stepping `n` periods back would underflow past year
-    /// `0` (i.e. there is no representable period that far in the past).
+    /// `0` (i.e. there is no representable period that far in the past), or
+    /// for daily periods that cannot be represented as a [`NaiveDate`].
     ///
     /// # Examples
     ///
@@ -904,88 +1620,535 @@ impl DatePeriod {
     /// ```
     pub fn pred_n(&self, n: u32) -> anyhow::Result<DatePeriod> {
         if n == 0 {
-            return Ok(self.clone());
+            return Ok(*self);
+        }
+        self.checked_pred_n(n).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Cannot go back {} periods from {} past year {}",
+                n,
+                self,
+                Self::MIN_YEAR
+            )
+        })
+    }
+
+    /// Offset this period by n steps
+    ///
+    /// Positive n advances forward, negative n goes backward.
+    /// If n is 0, returns the current period.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error from the underlying [`DatePeriod::succ_n`] /
+    /// [`DatePeriod::pred_n`] call — most notably when a negative offset would
+    /// underflow past year `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let period = DatePeriod::month(2024, 5).unwrap();
+    /// let next_period = period.offset_n(3).unwrap();
+    /// assert_eq!(next_period.to_string(), "2024M8");
+    ///
+    /// let prev_period = period.offset_n(-3).unwrap();
+    /// assert_eq!(prev_period.to_string(), "2024M2");
+    /// ```
+    pub fn offset_n(&self, n: i32) -> anyhow::Result<DatePeriod> {
+        match n.cmp(&0) {
+            std::cmp::Ordering::Equal => Ok(*self),
+            std::cmp::Ordering::Greater => self.succ_n(n as u32),
+            std::cmp::Ordering::Less => self.pred_n(n.unsigned_abs()),
+        }
+    }
+
+    /// Get the successor n periods ahead, or `None` if it would lie after
+    /// [`DatePeriod::MAX_YEAR`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let period = DatePeriod::quarter(2024, 4).unwrap();
+    /// assert_eq!(period.checked_succ_n(1), Some(DatePeriod::quarter(2025, 1).unwrap()));
+    /// assert_eq!(period.checked_succ_n(u32::MAX), None);
+    /// ```
+    pub fn checked_succ_n(&self, n: u32) -> Option<DatePeriod> {
+        self.checked_step(n as i64)
+    }
+
+    /// Get the predecessor n periods back, or `None` if it would lie before
+    /// [`DatePeriod::MIN_YEAR`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let period = DatePeriod::month(1, 1).unwrap();
+    /// assert_eq!(period.checked_pred_n(12), Some(DatePeriod::month(0, 1).unwrap()));
+    /// assert_eq!(period.checked_pred_n(13), None);
+    /// ```
+    pub fn checked_pred_n(&self, n: u32) -> Option<DatePeriod> {
+        self.checked_step(-(n as i64))
+    }
+
+    /// Offset this period by n steps, or `None` if the result would lie
+    /// outside [`DatePeriod::MIN_YEAR`]`..=`[`DatePeriod::MAX_YEAR`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let period = DatePeriod::year(2024).unwrap();
+    /// assert_eq!(period.checked_offset_n(-24), Some(DatePeriod::year(2000).unwrap()));
+    /// assert_eq!(period.checked_offset_n(-2025), None);
+    /// assert_eq!(period.checked_offset_n(i32::MAX), None);
+    /// ```
+    pub fn checked_offset_n(&self, n: i32) -> Option<DatePeriod> {
+        self.checked_step(n as i64)
+    }
+
+    /// Get the successor n periods ahead, stopping at the last period of
+    /// [`DatePeriod::MAX_YEAR`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let period = DatePeriod::month(2024, 5).unwrap();
+    /// assert_eq!(period.saturating_succ_n(3), DatePeriod::month(2024, 8).unwrap());
+    /// assert_eq!(
+    ///     period.saturating_succ_n(u32::MAX),
+    ///     DatePeriod::month(DatePeriod::MAX_YEAR, 12).unwrap()
+    /// );
+    /// ```
+    pub fn saturating_succ_n(&self, n: u32) -> DatePeriod {
+        self.saturating_step(n as i64)
+    }
+
+    /// Get the predecessor n periods back, stopping at the first period of
+    /// [`DatePeriod::MIN_YEAR`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let period = DatePeriod::daily(2, 1).unwrap();
+    /// assert_eq!(period.saturating_pred_n(1), DatePeriod::daily(1, 365).unwrap());
+    /// assert_eq!(period.saturating_pred_n(10_000), DatePeriod::daily(0, 1).unwrap());
+    /// ```
+    pub fn saturating_pred_n(&self, n: u32) -> DatePeriod {
+        self.saturating_step(-(n as i64))
+    }
+
+    /// Offset this period by n steps, or `None` if the result would lie
+    /// outside [`DatePeriod::MIN_YEAR`]`..=`[`DatePeriod::MAX_YEAR`]
+    ///
+    /// Like [`DatePeriod::checked_offset_n`] but taking an `i64`; this is the
+    /// non-panicking form of `period + n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let period = DatePeriod::quarter(2024, 1).unwrap();
+    /// assert_eq!(period.checked_add(3), Some(DatePeriod::quarter(2024, 4).unwrap()));
+    /// assert_eq!(period.checked_add(-1), Some(DatePeriod::quarter(2023, 4).unwrap()));
+    /// assert_eq!(period.checked_add(i64::MAX), None);
+    /// ```
+    pub fn checked_add(&self, n: i64) -> Option<DatePeriod> {
+        // Any two supported periods are fewer than i32::MAX steps apart, so
+        // offsets that don't fit offset_n are out of range anyway
+        self.offset_n(i32::try_from(n).ok()?).ok()
+    }
+
+    /// Offset this period by -n steps, or `None` if the result would lie
+    /// outside [`DatePeriod::MIN_YEAR`]`..=`[`DatePeriod::MAX_YEAR`]
+    ///
+    /// This is the non-panicking form of `period - n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let period = DatePeriod::month(2024, 1).unwrap();
+    /// assert_eq!(period.checked_sub(1), Some(DatePeriod::month(2023, 12).unwrap()));
+    /// assert_eq!(period.checked_sub(i64::MIN), None);
+    /// ```
+    pub fn checked_sub(&self, n: i64) -> Option<DatePeriod> {
+        self.checked_add(n.checked_neg()?)
+    }
+
+    /// Get the position of this period, counted in periods of its kind from
+    /// the start of year 0
+    ///
+    /// The epoch is `0Y`, `0Q1`, `0M1` or `0D1` (0000-01-01 in the proleptic
+    /// Gregorian calendar) depending on the kind, so consecutive periods have
+    /// consecutive ordinals. Use it as a stable array index or to compute
+    /// distances; [`DatePeriod::from_ordinal`] is the inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// assert_eq!(DatePeriod::month(0, 1).unwrap().ordinal(), 0);
+    /// assert_eq!(DatePeriod::month(2024, 3).unwrap().ordinal(), 2024 * 12 + 2);
+    /// assert_eq!(DatePeriod::daily(1, 1).unwrap().ordinal(), 366);
+    /// ```
+    pub fn ordinal(&self) -> i64 {
+        match self {
+            DatePeriod::Year(year) => *year as i64,
+            DatePeriod::Quarter(year, quarter) => *year as i64 * 4 + *quarter as i64 - 1,
+            DatePeriod::Month(year, month) => *year as i64 * 12 + *month as i64 - 1,
+            DatePeriod::Daily(year, day) => days_before_year(*year as i64) + *day as i64 - 1,
+        }
+    }
+
+    /// Create the period of `kind` at position `ordinal`
+    ///
+    /// Inverse of [`DatePeriod::ordinal`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `ordinal` is negative or lies after the last period
+    /// of [`DatePeriod::MAX_YEAR`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::{DatePeriod, PeriodKind};
+    ///
+    /// let month = DatePeriod::from_ordinal(PeriodKind::Month, 2024 * 12 + 2).unwrap();
+    /// assert_eq!(month.to_string(), "2024M3");
+    /// assert!(DatePeriod::from_ordinal(PeriodKind::Quarter, -1).is_err());
+    /// ```
+    pub fn from_ordinal(kind: PeriodKind, ordinal: i64) -> anyhow::Result<Self> {
+        Self::from_index(kind, ordinal).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Ordinal must be between 0 and {} for {} periods, got: {}",
+                Self::max_index(kind),
+                kind,
+                ordinal
+            )
+        })
+    }
+
+    /// Get the number of periods from this period to `other`
+    ///
+    /// The result is positive when `other` is later, negative when it is
+    /// earlier and `0` when both are equal, so
+    /// `self.offset_n(n)? == other` for `n = self.distance_to(&other)?`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the two periods are of different kinds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    ///
+    /// let from = DatePeriod::month(2019, 7).unwrap();
+    /// let to = DatePeriod::month(2024, 3).unwrap();
+    /// assert_eq!(from.distance_to(&to).unwrap(), 56);
+    /// assert_eq!(to.distance_to(&from).unwrap(), -56);
+    /// assert!(from.distance_to(&DatePeriod::year(2024).unwrap()).is_err());
+    /// ```
+    pub fn distance_to(&self, other: &DatePeriod) -> anyhow::Result<i64> {
+        if self.kind() != other.kind() {
+            return Err(anyhow::anyhow!(
+                "Cannot measure the distance between {} and {} of different kinds",
+                self,
+                other
+            ));
+        }
+        Ok(other.ordinal() - self.ordinal())
+    }
+
+    /// Inverse of [`DatePeriod::ordinal`], `None` outside the supported years
+    fn from_index(kind: PeriodKind, index: i64) -> Option<DatePeriod> {
+        if !(0..=Self::max_index(kind)).contains(&index) {
+            return None;
+        }
+        Some(match kind {
+            PeriodKind::Year => DatePeriod::Year(index as u32),
+            PeriodKind::Quarter => DatePeriod::Quarter((index / 4) as u32, (index % 4) as u32 + 1),
+            PeriodKind::Month => DatePeriod::Month((index / 12) as u32, (index % 12) as u32 + 1),
+            PeriodKind::Daily => {
+                let cycles = index / DAYS_PER_400_YEARS;
+                let remaining = index % DAYS_PER_400_YEARS;
+                // Every year has at least 365 days, so this over-estimates by at most one
+                let mut year = remaining / 365;
+                while days_before_year(year) > remaining {
+                    year -= 1;
+                }
+                DatePeriod::Daily(
+                    (cycles * 400 + year) as u32,
+                    (remaining - days_before_year(year) + 1) as u32,
+                )
+            }
+        })
+    }
+
+    /// Index of the last period of `kind` in [`DatePeriod::MAX_YEAR`]
+    fn max_index(kind: PeriodKind) -> i64 {
+        match kind {
+            PeriodKind::Year => Self::MAX_YEAR as i64,
+            PeriodKind::Quarter => Self::MAX_YEAR as i64 * 4 + 3,
+            PeriodKind::Month => Self::MAX_YEAR as i64 * 12 + 11,
+            PeriodKind::Daily => days_before_year(Self::MAX_YEAR as i64 + 1) - 1,
+        }
+    }
+
+    fn checked_step(&self, n: i64) -> Option<DatePeriod> {
+        Self::from_index(self.kind(), self.ordinal().checked_add(n)?)
+    }
+
+    fn saturating_step(&self, n: i64) -> DatePeriod {
+        let kind = self.kind();
+        let index = self
+            .ordinal()
+            .saturating_add(n)
+            .clamp(0, Self::max_index(kind));
+        Self::from_index(kind, index).unwrap_or(*self)
+    }
+}
+
+/// Lazy iterator over the sub-periods of a [`DatePeriod`]
+///
+/// Created by [`DatePeriod::expand_to`].
+#[derive(Debug, Clone)]
+pub struct ExpandTo {
+    kind: PeriodKind,
+    next: i64,
+    end: i64,
+}
+
+impl Iterator for ExpandTo {
+    type Item = DatePeriod;
+
+    fn next(&mut self) -> Option<DatePeriod> {
+        if self.next >= self.end {
+            return None;
+        }
+        let period = DatePeriod::from_index(self.kind, self.next)?;
+        self.next += 1;
+        Some(period)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end - self.next).max(0) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for ExpandTo {
+    fn next_back(&mut self) -> Option<DatePeriod> {
+        if self.next >= self.end {
+            return None;
+        }
+        let period = DatePeriod::from_index(self.kind, self.end - 1)?;
+        self.end -= 1;
+        Some(period)
+    }
+}
+
+impl ExactSizeIterator for ExpandTo {}
+
+impl std::iter::FusedIterator for ExpandTo {}
+
+impl Period for DatePeriod {
+    type Parent = DatePeriod;
+    type Child = DatePeriod;
+
+    fn get_first_day(&self) -> anyhow::Result<NaiveDate> {
+        DatePeriod::get_first_day(self)
+    }
+
+    fn get_last_day(&self) -> anyhow::Result<NaiveDate> {
+        DatePeriod::get_last_day(self)
+    }
+
+    fn checked_add(&self, n: i64) -> Option<Self> {
+        DatePeriod::checked_add(self, n)
+    }
+
+    fn containing(&self, date: NaiveDate) -> anyhow::Result<Self> {
+        DatePeriod::from_date(date, self.kind())
+    }
+
+    fn parent(&self) -> Option<Self> {
+        self.kind().parent().map(|_| self.aggregate())
+    }
+
+    fn children(&self) -> Vec<Self> {
+        self.decompose()
+    }
+
+    fn contains_date(&self, date: NaiveDate) -> bool {
+        DatePeriod::contains_date(self, date)
+    }
+
+    fn succ_n(&self, n: u32) -> anyhow::Result<Self> {
+        DatePeriod::succ_n(self, n)
+    }
+
+    fn pred_n(&self, n: u32) -> anyhow::Result<Self> {
+        DatePeriod::pred_n(self, n)
+    }
+
+    fn offset_n(&self, n: i32) -> anyhow::Result<Self> {
+        DatePeriod::offset_n(self, n)
+    }
+}
+
+/// Offset a period by `n` steps of its own kind
+///
+/// # Panics
+///
+/// Panics if the result would lie outside
+/// [`DatePeriod::MIN_YEAR`]`..=`[`DatePeriod::MAX_YEAR`]; use
+/// [`DatePeriod::checked_add`] or [`DatePeriod::offset_n`] to handle that case.
+///
+/// # Examples
+///
+/// ```
+/// use range_date::range_type::DatePeriod;
+///
+/// let mut month = DatePeriod::month(2024, 11).unwrap();
+/// assert_eq!(month + 3, DatePeriod::month(2025, 2).unwrap());
+/// month += 2;
+/// assert_eq!(month.to_string(), "2025M1");
+/// ```
+impl Add<i64> for DatePeriod {
+    type Output = DatePeriod;
+
+    #[allow(clippy::panic)]
+    fn add(self, n: i64) -> DatePeriod {
+        match self.checked_add(n) {
+            Some(period) => period,
+            None => panic!("{} + {} lies outside the supported years", self, n),
         }
-        Ok(match self {
-            DatePeriod::Year(year) => {
-                if *year >= n {
-                    DatePeriod::Year(year - n)
-                } else {
-                    anyhow::bail!("Cannot go back {} years from year {}", n, year);
-                }
-            }
-            DatePeriod::Quarter(year, quarter) => {
-                let total_quarters = (*year as i64 * 4) + (*quarter as i64 - 1) - n as i64;
-                if total_quarters < 0 {
-                    anyhow::bail!("Cannot go back {} quarters from {}", n, self);
-                }
-                let new_year = (total_quarters / 4) as u32;
-                let new_quarter = ((total_quarters % 4) + 1) as u32;
-                DatePeriod::Quarter(new_year, new_quarter)
-            }
-            DatePeriod::Month(year, month) => {
-                let total_months = (*year as i64 * 12) + (*month as i64 - 1) - n as i64;
-                if total_months < 0 {
-                    anyhow::bail!("Cannot go back {} months from {}", n, self);
-                }
-                let new_year = (total_months / 12) as u32;
-                let new_month = ((total_months % 12) + 1) as u32;
-                DatePeriod::Month(new_year, new_month)
-            }
-            DatePeriod::Daily(year, day) => {
-                let mut current_year = *year as i32;
-                let mut current_day = *day as i32;
-                let mut remaining = n as i32;
-                while remaining > 0 {
-                    if remaining < current_day {
-                        current_day -= remaining;
-                        remaining = 0;
-                    } else {
-                        remaining -= current_day;
-                        current_year -= 1;
-                        if current_year < 0 {
-                            anyhow::bail!("Cannot go back {} days from {}", n, self);
-                        }
-                        current_day = if leap_year(current_year) { 366 } else { 365 };
-                    }
-                }
-                DatePeriod::Daily(current_year as u32, current_day as u32)
-            }
-        })
     }
+}
 
-    /// Offset this period by n steps
-    ///
-    /// Positive n advances forward, negative n goes backward.
-    /// If n is 0, returns the current period.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error from the underlying [`DatePeriod::succ_n`] /
-    /// [`DatePeriod::pred_n`] call — most notably when a negative offset would
-    /// underflow past year `0`.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use range_date::range_type::DatePeriod;
-    ///
-    /// let period = DatePeriod::month(2024, 5).unwrap();
-    /// let next_period = period.offset_n(3).unwrap();
-    /// assert_eq!(next_period.to_string(), "2024M8");
-    ///
-    /// let prev_period = period.offset_n(-3).unwrap();
-    /// assert_eq!(prev_period.to_string(), "2024M2");
-    /// ```
-    pub fn offset_n(&self, n: i32) -> anyhow::Result<DatePeriod> {
-        match n.cmp(&0) {
-            std::cmp::Ordering::Equal => Ok(self.clone()),
-            std::cmp::Ordering::Greater => self.succ_n(n as u32),
-            std::cmp::Ordering::Less => self.pred_n((-n) as u32),
+/// Offset a period by `-n` steps of its own kind
+///
+/// # Panics
+///
+/// Panics if the result would lie outside
+/// [`DatePeriod::MIN_YEAR`]`..=`[`DatePeriod::MAX_YEAR`]; use
+/// [`DatePeriod::checked_sub`] or [`DatePeriod::offset_n`] to handle that case.
+///
+/// # Examples
+///
+/// ```
+/// use range_date::range_type::DatePeriod;
+///
+/// let mut day = DatePeriod::daily(2024, 1).unwrap();
+/// assert_eq!(day - 1, DatePeriod::daily(2023, 365).unwrap());
+/// day -= 365;
+/// assert_eq!(day.to_string(), "2023D1");
+/// ```
+impl Sub<i64> for DatePeriod {
+    type Output = DatePeriod;
+
+    #[allow(clippy::panic)]
+    fn sub(self, n: i64) -> DatePeriod {
+        match self.checked_sub(n) {
+            Some(period) => period,
+            None => panic!("{} - {} lies outside the supported years", self, n),
+        }
+    }
+}
+
+impl AddAssign<i64> for DatePeriod {
+    fn add_assign(&mut self, n: i64) {
+        *self = *self + n;
+    }
+}
+
+impl SubAssign<i64> for DatePeriod {
+    fn sub_assign(&mut self, n: i64) {
+        *self = *self - n;
+    }
+}
+
+/// Number of periods from `other` to `self`
+///
+/// `a - b` equals `b.distance_to(&a)`, so `b + (a - b) == a`.
+///
+/// # Panics
+///
+/// Panics if the periods are of different kinds; use
+/// [`DatePeriod::distance_to`] to handle that case.
+///
+/// # Examples
+///
+/// ```
+/// use range_date::range_type::DatePeriod;
+///
+/// let from = DatePeriod::month(2019, 7).unwrap();
+/// let to = DatePeriod::month(2024, 3).unwrap();
+/// assert_eq!(to - from, 56);
+/// assert_eq!(from + (to - from), to);
+/// ```
+impl Sub<DatePeriod> for DatePeriod {
+    type Output = i64;
+
+    #[allow(clippy::panic)]
+    fn sub(self, other: DatePeriod) -> i64 {
+        match other.distance_to(&self) {
+            Ok(distance) => distance,
+            Err(e) => panic!("{}", e),
         }
     }
 }
 
+/// Length of a 400-year Gregorian cycle in days.
+const DAYS_PER_400_YEARS: i64 = 146_097;
+
+/// Number of days from 0000-01-01 to January 1st of `year` (`year >= 0`) in
+/// the proleptic Gregorian calendar
+const fn days_before_year(year: i64) -> i64 {
+    365 * year + (year + 3) / 4 - (year + 99) / 100 + (year + 399) / 400
+}
+
+/// Get the first instant of `date` in the time zone `tz`
+///
+/// Local midnight may be skipped by a DST transition, in which case the day
+/// starts at the first local minute after the gap that exists in `tz`.
+fn local_day_start<Tz: TimeZone>(date: NaiveDate, tz: &Tz) -> anyhow::Result<DateTime<Tz>> {
+    local_start(date.and_time(NaiveTime::MIN), tz)
+}
+
+/// Get the first instant at or after the local time `local` in `tz`
+///
+/// Ambiguous local times resolve to their earliest instant; local times
+/// skipped by a DST transition move to the first local minute after the gap.
+pub(crate) fn local_start<Tz: TimeZone>(
+    local: NaiveDateTime,
+    tz: &Tz,
+) -> anyhow::Result<DateTime<Tz>> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(dt) => Ok(dt),
+        LocalResult::Ambiguous(earliest, _) => Ok(earliest),
+        LocalResult::None => (1..24 * 60)
+            .map(|minutes| local + Duration::minutes(minutes))
+            .find_map(|local| tz.from_local_datetime(&local).earliest())
+            .ok_or_else(|| anyhow::anyhow!("No local instant for {} in time zone", local)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -997,7 +2160,7 @@ mod tests {
     #[test]
     fn test_constructors() {
         // Test year constructor
-        let year_period = DatePeriod::year(2024);
+        let year_period = DatePeriod::year(2024).unwrap();
         assert_eq!(year_period, DatePeriod::Year(2024));
 
         // Test quarter constructor with validation
@@ -1077,17 +2240,20 @@ mod tests {
         let date = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
 
         // Test conversion to different period types
-        assert_eq!(DatePeriod::from_date_as_year(date), DatePeriod::Year(2024));
         assert_eq!(
-            DatePeriod::from_date_as_quarter(date),
+            DatePeriod::from_date_as_year(date).unwrap(),
+            DatePeriod::Year(2024)
+        );
+        assert_eq!(
+            DatePeriod::from_date_as_quarter(date).unwrap(),
             DatePeriod::Quarter(2024, 2)
         );
         assert_eq!(
-            DatePeriod::from_date_as_month(date),
+            DatePeriod::from_date_as_month(date).unwrap(),
             DatePeriod::Month(2024, 5)
         );
         assert_eq!(
-            DatePeriod::from_date_as_daily(date),
+            DatePeriod::from_date_as_daily(date).unwrap(),
             DatePeriod::Daily(2024, 136)
         ); // May 15 is 136th day
     }
@@ -1095,7 +2261,7 @@ mod tests {
     #[test]
     fn test_get_first_and_last_day() -> anyhow::Result<()> {
         // Test year
-        let year_period = DatePeriod::year(2024);
+        let year_period = DatePeriod::year(2024).unwrap();
         assert_eq!(
             year_period.get_first_day()?,
             NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
@@ -1157,6 +2323,7 @@ mod tests {
         assert_eq!(quarter_period.value(), 2);
         assert_eq!(quarter_period.short_name(), "Q");
         assert_eq!(quarter_period.period_name(), "QUARTER");
+        assert_eq!(quarter_period.kind(), PeriodKind::Quarter);
 
         let month_period = DatePeriod::month(2024, 5).unwrap();
         assert_eq!(month_period.get_year(), 2024);
@@ -1167,7 +2334,7 @@ mod tests {
 
     #[test]
     fn test_to_string() {
-        assert_eq!(DatePeriod::year(2024).to_string(), "2024Y");
+        assert_eq!(DatePeriod::year(2024).unwrap().to_string(), "2024Y");
         assert_eq!(DatePeriod::quarter(2024, 2).unwrap().to_string(), "2024Q2");
         assert_eq!(DatePeriod::month(2024, 5).unwrap().to_string(), "2024M5");
         assert_eq!(
@@ -1206,7 +2373,7 @@ mod tests {
     fn test_succ() {
         // Test year
         assert_eq!(
-            DatePeriod::year(2024).succ().unwrap(),
+            DatePeriod::year(2024).unwrap().succ().unwrap(),
             DatePeriod::Year(2025)
         );
 
@@ -1249,10 +2416,10 @@ mod tests {
     fn test_pred() {
         // Test year
         assert_eq!(
-            DatePeriod::year(2024).pred().unwrap(),
+            DatePeriod::year(2024).unwrap().pred().unwrap(),
             DatePeriod::Year(2023)
         );
-        assert!(DatePeriod::year(0).pred().is_err());
+        assert!(DatePeriod::year(0).unwrap().pred().is_err());
 
         // Test quarter
         assert_eq!(
@@ -1291,7 +2458,7 @@ mod tests {
     #[test]
     fn test_decompose() {
         // Test year
-        let year_decomposed = DatePeriod::year(2025).decompose();
+        let year_decomposed = DatePeriod::year(2025).unwrap().decompose();
         assert_eq!(year_decomposed.len(), 4);
         assert_eq!(year_decomposed[0], DatePeriod::Quarter(2025, 1));
         assert_eq!(year_decomposed[3], DatePeriod::Quarter(2025, 4));
@@ -1305,13 +2472,24 @@ mod tests {
         // Test month (non-leap)
         let month_decomposed = DatePeriod::month(2023, 2).unwrap().decompose();
         assert_eq!(month_decomposed.len(), 28);
-        assert_eq!(month_decomposed[0], DatePeriod::Daily(2023, 1));
-        assert_eq!(month_decomposed[27], DatePeriod::Daily(2023, 28));
+        assert_eq!(month_decomposed[0], DatePeriod::Daily(2023, 32));
+        assert_eq!(month_decomposed[27], DatePeriod::Daily(2023, 59));
 
         // Test month (leap)
         let leap_month_decomposed = DatePeriod::month(2024, 2).unwrap().decompose();
         assert_eq!(leap_month_decomposed.len(), 29);
-        assert_eq!(leap_month_decomposed[28], DatePeriod::Daily(2024, 29));
+        assert_eq!(leap_month_decomposed[28], DatePeriod::Daily(2024, 60));
+
+        // Days of every month line up with the month
+        for month in DatePeriod::year(2024)
+            .unwrap()
+            .expand_to(PeriodKind::Month)
+            .unwrap()
+        {
+            for day in month.decompose() {
+                assert_eq!(day.aggregate(), month);
+            }
+        }
 
         // Test daily
         let daily_decomposed = DatePeriod::daily(2024, 1).unwrap().decompose();
@@ -1339,7 +2517,10 @@ mod tests {
         );
 
         // Test year
-        assert_eq!(DatePeriod::year(2025).aggregate(), DatePeriod::Year(2025));
+        assert_eq!(
+            DatePeriod::year(2025).unwrap().aggregate(),
+            DatePeriod::Year(2025)
+        );
     }
 
     #[test]
@@ -1459,14 +2640,14 @@ mod tests {
         assert_eq!(period.pred_n(0).unwrap(), period);
 
         // Test succ_n for Year
-        let year_period = DatePeriod::year(2024);
+        let year_period = DatePeriod::year(2024).unwrap();
         assert_eq!(year_period.succ_n(1).unwrap(), DatePeriod::Year(2025));
         assert_eq!(year_period.succ_n(5).unwrap(), DatePeriod::Year(2029));
 
         // Test pred_n for Year
         assert_eq!(year_period.pred_n(1).unwrap(), DatePeriod::Year(2023));
         assert_eq!(year_period.pred_n(5).unwrap(), DatePeriod::Year(2019));
-        assert!(DatePeriod::year(2).pred_n(5).is_err()); // Cannot go back beyond 0
+        assert!(DatePeriod::year(2).unwrap().pred_n(5).is_err()); // Cannot go back beyond 0
 
         // Test succ_n for Quarter
         let quarter_period = DatePeriod::quarter(2024, 2).unwrap();
@@ -1558,7 +2739,7 @@ mod tests {
         );
 
         // Test error cases
-        assert!(DatePeriod::year(1).offset_n(-2).is_err()); // Year 0 error
+        assert!(DatePeriod::year(1).unwrap().offset_n(-2).is_err()); // Year 0 error
 
         // Test consistency with succ_n and pred_n
         assert_eq!(
@@ -1570,4 +2751,735 @@ mod tests {
             test_period.pred_n(5).unwrap()
         );
     }
+
+    /// Test zone at UTC-4 that observes UTC-3 between 2024-09-08 and
+    /// 2025-04-06, switching at local midnight (like America/Santiago).
+    #[derive(Debug, Clone, Copy)]
+    struct MidnightDstZone;
+
+    impl MidnightDstZone {
+        fn offset_at(utc: &chrono::NaiveDateTime) -> chrono::FixedOffset {
+            let dst_start = NaiveDate::from_ymd_opt(2024, 9, 8)
+                .unwrap()
+                .and_hms_opt(4, 0, 0)
+                .unwrap();
+            let dst_end = NaiveDate::from_ymd_opt(2025, 4, 6)
+                .unwrap()
+                .and_hms_opt(3, 0, 0)
+                .unwrap();
+            let hours = if *utc >= dst_start && *utc < dst_end {
+                3
+            } else {
+                4
+            };
+            chrono::FixedOffset::west_opt(hours * 3600).unwrap()
+        }
+    }
+
+    impl TimeZone for MidnightDstZone {
+        type Offset = chrono::FixedOffset;
+
+        fn from_offset(_: &chrono::FixedOffset) -> Self {
+            MidnightDstZone
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<chrono::FixedOffset> {
+            self.offset_from_local_datetime(&local.and_time(chrono::NaiveTime::MIN))
+        }
+
+        fn offset_from_local_datetime(
+            &self,
+            local: &chrono::NaiveDateTime,
+        ) -> LocalResult<chrono::FixedOffset> {
+            let candidates: Vec<_> = [4, 3]
+                .into_iter()
+                .map(|h| chrono::FixedOffset::west_opt(h * 3600).unwrap())
+                .filter(|off| Self::offset_at(&(*local - *off)) == *off)
+                .collect();
+            match candidates[..] {
+                [] => LocalResult::None,
+                [off] => LocalResult::Single(off),
+                [standard, summer] => LocalResult::Ambiguous(summer, standard),
+                _ => unreachable!(),
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> chrono::FixedOffset {
+            self.offset_from_utc_datetime(&utc.and_time(chrono::NaiveTime::MIN))
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &chrono::NaiveDateTime) -> chrono::FixedOffset {
+            Self::offset_at(utc)
+        }
+    }
+
+    #[test]
+    fn test_instants_across_dst() {
+        let utc = |y, m, d, h| chrono::Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap();
+
+        // Midnight 2024-09-08 does not exist: the day starts at 01:00 (-03:00)
+        let gap_day =
+            DatePeriod::from_date_as_daily(NaiveDate::from_ymd_opt(2024, 9, 8).unwrap()).unwrap();
+        let start = gap_day.start_instant(&MidnightDstZone).unwrap();
+        let end = gap_day.end_instant(&MidnightDstZone).unwrap();
+        assert_eq!(start, utc(2024, 9, 8, 4));
+        assert_eq!(end, utc(2024, 9, 9, 3));
+        assert_eq!(end - start, Duration::hours(23));
+
+        // The preceding day ends exactly where the gap day starts
+        assert_eq!(
+            gap_day
+                .pred()
+                .unwrap()
+                .end_instant(&MidnightDstZone)
+                .unwrap(),
+            start
+        );
+
+        // Clocks go back at midnight 2025-04-06 (-03:00), repeating 23:00 on
+        // 2025-04-05: that day is 25 hours long
+        let long_day =
+            DatePeriod::from_date_as_daily(NaiveDate::from_ymd_opt(2025, 4, 5).unwrap()).unwrap();
+        let start = long_day.start_instant(&MidnightDstZone).unwrap();
+        let end = long_day.end_instant(&MidnightDstZone).unwrap();
+        assert_eq!(start, utc(2025, 4, 5, 3));
+        assert_eq!(end, utc(2025, 4, 6, 4));
+        assert_eq!(end - start, Duration::hours(25));
+
+        // Quarter boundaries in standard and summer time
+        let q3 = DatePeriod::quarter(2024, 3).unwrap();
+        assert_eq!(
+            q3.start_instant(&MidnightDstZone).unwrap(),
+            utc(2024, 7, 1, 4)
+        );
+        assert_eq!(
+            q3.end_instant(&MidnightDstZone).unwrap(),
+            utc(2024, 10, 1, 3)
+        );
+        assert_eq!(
+            q3.end_instant(&MidnightDstZone).unwrap(),
+            q3.succ().unwrap().start_instant(&MidnightDstZone).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_datetime() {
+        // 03:30 UTC on 2024-09-08 is still 23:30 on 2024-09-07 locally
+        let dt = chrono::Utc.with_ymd_and_hms(2024, 9, 8, 3, 30, 0).unwrap();
+        assert_eq!(
+            DatePeriod::from_datetime_as_daily(&dt, &MidnightDstZone).unwrap(),
+            DatePeriod::Daily(2024, 251)
+        );
+        assert_eq!(
+            DatePeriod::from_datetime_as_daily(&dt, &chrono::Utc).unwrap(),
+            DatePeriod::Daily(2024, 252)
+        );
+
+        // New Year's Eve in UTC is already the next year in UTC+9
+        let dt = chrono::Utc
+            .with_ymd_and_hms(2024, 12, 31, 15, 0, 0)
+            .unwrap();
+        let tokyo = chrono::FixedOffset::east_opt(9 * 3600).unwrap();
+        assert_eq!(
+            DatePeriod::from_datetime_as_year(&dt, &tokyo).unwrap(),
+            DatePeriod::Year(2025)
+        );
+        assert_eq!(
+            DatePeriod::from_datetime_as_quarter(&dt, &tokyo).unwrap(),
+            DatePeriod::Quarter(2025, 1)
+        );
+        assert_eq!(
+            DatePeriod::from_datetime_as_month(&dt, &tokyo).unwrap(),
+            DatePeriod::Month(2025, 1)
+        );
+
+        // An instant always lies within the period built from it
+        let period = DatePeriod::from_datetime_as_month(&dt, &MidnightDstZone).unwrap();
+        assert!(period.start_instant(&MidnightDstZone).unwrap() <= dt);
+        assert!(dt < period.end_instant(&MidnightDstZone).unwrap());
+    }
+
+    /// Day-by-year stepping that `succ_n` used before switching to day counts.
+    fn reference_succ_n(year: u32, day: u32, n: u32) -> DatePeriod {
+        let mut current_year = year;
+        let mut current_day = day;
+        let mut remaining = n;
+        while remaining > 0 {
+            let max_days = if leap_year(current_year as i32) {
+                366
+            } else {
+                365
+            };
+            if current_day + remaining <= max_days {
+                current_day += remaining;
+                break;
+            } else {
+                remaining -= max_days - current_day + 1;
+                current_year += 1;
+                current_day = 1;
+            }
+        }
+        DatePeriod::Daily(current_year, current_day)
+    }
+
+    /// Day-by-year stepping that `pred_n` used before switching to day counts.
+    fn reference_pred_n(year: u32, day: u32, n: u32) -> Option<DatePeriod> {
+        let mut current_year = year as i64;
+        let mut current_day = day as i64;
+        let mut remaining = n as i64;
+        while remaining > 0 {
+            if remaining < current_day {
+                current_day -= remaining;
+                remaining = 0;
+            } else {
+                remaining -= current_day;
+                current_year -= 1;
+                if current_year < 0 {
+                    return None;
+                }
+                current_day = if leap_year(current_year as i32) {
+                    366
+                } else {
+                    365
+                };
+            }
+        }
+        Some(DatePeriod::Daily(current_year as u32, current_day as u32))
+    }
+
+    #[test]
+    fn test_daily_offsets_match_reference_loop() {
+        let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut next = |bound: u64| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (state >> 33) % bound
+        };
+        for _ in 0..20_000 {
+            let year = next(5_000) as u32;
+            let max_days = if leap_year(year as i32) { 366 } else { 365 };
+            let day = next(max_days) as u32 + 1;
+            // Mix small steps around year boundaries with large jumps
+            let n = match next(3) {
+                0 => next(800) as u32,
+                1 => next(100_000) as u32,
+                _ => next(2_000_000) as u32,
+            };
+            let period = DatePeriod::daily(year, day).unwrap();
+            assert_eq!(
+                period.succ_n(n).unwrap(),
+                reference_succ_n(year, day, n),
+                "{} + {}",
+                period,
+                n
+            );
+            assert_eq!(
+                period.pred_n(n).ok(),
+                reference_pred_n(year, day, n),
+                "{} - {}",
+                period,
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_daily_offsets_large_and_out_of_range() {
+        let period = DatePeriod::daily(2024, 60).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let expected = date + Duration::days(10_000_000);
+        assert_eq!(
+            period.succ_n(10_000_000).unwrap(),
+            DatePeriod::from_date_as_daily(expected).unwrap()
+        );
+        assert_eq!(
+            DatePeriod::from_date_as_daily(expected)
+                .unwrap()
+                .pred_n(10_000_000)
+                .unwrap(),
+            period
+        );
+
+        // Beyond chrono's range or before year 0
+        assert!(period.succ_n(u32::MAX).is_err());
+        assert!(period.pred_n(u32::MAX).is_err());
+        assert!(DatePeriod::daily(0, 1).unwrap().pred_n(1).is_err());
+        assert!(DatePeriod::Daily(u32::MAX, 1).succ_n(1).is_err());
+    }
+
+    #[test]
+    fn test_year_range_matches_chrono() {
+        assert_eq!(DatePeriod::MAX_YEAR as i32, NaiveDate::MAX.year());
+        assert_eq!(NaiveDate::MAX.month(), 12);
+        assert_eq!(NaiveDate::MAX.day(), 31);
+
+        // Every period at the edges of the range has computable boundaries
+        for period in [
+            DatePeriod::Year(DatePeriod::MIN_YEAR),
+            DatePeriod::Daily(DatePeriod::MIN_YEAR, 1),
+            DatePeriod::Year(DatePeriod::MAX_YEAR),
+            DatePeriod::Quarter(DatePeriod::MAX_YEAR, 4),
+            DatePeriod::Month(DatePeriod::MAX_YEAR, 12),
+            DatePeriod::Daily(DatePeriod::MAX_YEAR, 365),
+        ] {
+            assert!(period.get_first_day().is_ok(), "{}", period);
+            assert!(period.get_last_day().is_ok(), "{}", period);
+        }
+    }
+
+    #[test]
+    fn test_day_index_matches_chrono() {
+        let epoch = NaiveDate::from_ymd_opt(0, 1, 1).unwrap();
+        let mut date = epoch;
+        while date.year() < 2500 {
+            let period = DatePeriod::from_date_as_daily(date).unwrap();
+            let index = (date - epoch).num_days();
+            assert_eq!(period.ordinal(), index);
+            assert_eq!(
+                DatePeriod::from_index(PeriodKind::Daily, index),
+                Some(period)
+            );
+            date += Duration::days(97);
+        }
+        let last = DatePeriod::from_date_as_daily(NaiveDate::MAX).unwrap();
+        assert_eq!(last.ordinal(), (NaiveDate::MAX - epoch).num_days());
+        assert_eq!(last.checked_succ_n(1), None);
+    }
+
+    #[test]
+    fn test_checked_and_saturating_navigation() {
+        let max_year = DatePeriod::Year(DatePeriod::MAX_YEAR);
+        assert!(max_year.succ().is_err());
+        assert!(max_year.succ_n(1).is_err());
+        assert_eq!(max_year.checked_succ_n(1), None);
+        assert_eq!(max_year.saturating_succ_n(1), max_year);
+        assert_eq!(
+            max_year.checked_pred_n(1),
+            Some(DatePeriod::Year(DatePeriod::MAX_YEAR - 1))
+        );
+
+        let last_month = DatePeriod::Month(DatePeriod::MAX_YEAR, 12);
+        assert_eq!(last_month.checked_offset_n(1), None);
+        assert_eq!(
+            last_month.checked_offset_n(-12),
+            Some(DatePeriod::Month(DatePeriod::MAX_YEAR - 1, 12))
+        );
+        assert_eq!(
+            DatePeriod::Month(2024, 1).saturating_succ_n(u32::MAX),
+            last_month
+        );
+
+        let first_quarter = DatePeriod::Quarter(0, 1);
+        assert_eq!(first_quarter.checked_pred_n(1), None);
+        assert_eq!(first_quarter.saturating_pred_n(u32::MAX), first_quarter);
+        assert_eq!(
+            DatePeriod::Quarter(2024, 3).saturating_pred_n(5),
+            DatePeriod::Quarter(2023, 2)
+        );
+        assert_eq!(first_quarter.checked_offset_n(i32::MIN), None);
+
+        let last_day = DatePeriod::Daily(DatePeriod::MAX_YEAR, 365);
+        assert_eq!(last_day.checked_succ_n(1), None);
+        assert_eq!(
+            DatePeriod::Daily(2024, 1).saturating_succ_n(u32::MAX),
+            last_day
+        );
+        assert_eq!(
+            DatePeriod::Daily(2024, 1).saturating_pred_n(u32::MAX),
+            DatePeriod::Daily(0, 1)
+        );
+    }
+
+    #[test]
+    fn test_between_dates_at_max_year() {
+        let start = NaiveDate::from_ymd_opt(DatePeriod::MAX_YEAR as i32, 11, 15).unwrap();
+        assert_eq!(
+            DatePeriod::between_date_as_month(start, NaiveDate::MAX).unwrap(),
+            vec![
+                DatePeriod::Month(DatePeriod::MAX_YEAR, 11),
+                DatePeriod::Month(DatePeriod::MAX_YEAR, 12)
+            ]
+        );
+        assert_eq!(
+            DatePeriod::between_date_as_quarter(start, NaiveDate::MAX).unwrap(),
+            vec![DatePeriod::Quarter(DatePeriod::MAX_YEAR, 4)]
+        );
+        let days = DatePeriod::between_date_as_daily(start, NaiveDate::MAX).unwrap();
+        assert_eq!(days.len(), 47);
+        assert_eq!(
+            days.last(),
+            Some(&DatePeriod::Daily(DatePeriod::MAX_YEAR, 365))
+        );
+    }
+
+    #[test]
+    fn test_navigation_never_panics() {
+        let periods = [
+            DatePeriod::Year(0),
+            DatePeriod::Year(DatePeriod::MAX_YEAR),
+            DatePeriod::Year(u32::MAX),
+            DatePeriod::Quarter(0, 1),
+            DatePeriod::Quarter(u32::MAX, 4),
+            DatePeriod::Month(0, 1),
+            DatePeriod::Month(u32::MAX, 12),
+            DatePeriod::Daily(0, 1),
+            DatePeriod::Daily(DatePeriod::MAX_YEAR, 365),
+            DatePeriod::Daily(u32::MAX, 366),
+        ];
+        let steps = [0, 1, 2, 12, 366, i32::MAX as u32, u32::MAX];
+        let offsets = [0, 1, -1, i32::MAX, i32::MIN, i32::MIN + 1];
+
+        for period in &periods {
+            let _ = period.succ();
+            let _ = period.pred();
+            for n in steps {
+                let _ = period.succ_n(n);
+                let _ = period.pred_n(n);
+                let _ = period.checked_succ_n(n);
+                let _ = period.checked_pred_n(n);
+                let _ = period.saturating_succ_n(n);
+                let _ = period.saturating_pred_n(n);
+            }
+            for n in offsets {
+                let _ = period.offset_n(n);
+                let _ = period.checked_offset_n(n);
+            }
+        }
+
+        // Results of navigation always stay within the supported years
+        for period in &periods {
+            for n in steps {
+                for result in [period.saturating_succ_n(n), period.saturating_pred_n(n)] {
+                    assert!(result.get_year() <= DatePeriod::MAX_YEAR);
+                    assert!(result.get_first_day().is_ok());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bits_round_trip_and_order() {
+        let mut periods = vec![
+            DatePeriod::year(0).unwrap(),
+            DatePeriod::year(DatePeriod::MAX_YEAR).unwrap(),
+            DatePeriod::daily(DatePeriod::MAX_YEAR, 365).unwrap(),
+            DatePeriod::daily(2024, 366).unwrap(),
+        ];
+        let mut period = DatePeriod::month(2019, 7).unwrap();
+        for _ in 0..100 {
+            periods.push(period);
+            periods.push(period.aggregate());
+            periods.push(DatePeriod::from_date_as_daily(period.get_last_day().unwrap()).unwrap());
+            period = period.succ_n(7).unwrap();
+        }
+
+        for period in &periods {
+            assert_eq!(DatePeriod::from_bits(period.to_bits()).unwrap(), *period);
+        }
+        for a in &periods {
+            for b in &periods {
+                assert_eq!(a.cmp(b), a.to_bits().cmp(&b.to_bits()), "{} vs {}", a, b);
+            }
+        }
+
+        assert_eq!(DatePeriod::year(2024).unwrap().to_bits(), 2024 << 24);
+        assert!(DatePeriod::from_bits((2024 << 24) | 1).is_err());
+        assert!(DatePeriod::from_bits((4 << 56) | (2024 << 24) | 1).is_err());
+        assert!(DatePeriod::from_bits((3 << 56) | (2023 << 24) | 366).is_err());
+        assert!(DatePeriod::from_bits(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_ordinal_and_distance() {
+        let a = DatePeriod::month(2019, 7).unwrap();
+        let b = DatePeriod::month(2024, 3).unwrap();
+        assert_eq!(a.distance_to(&b).unwrap(), 4 * 12 + 8);
+        assert_eq!(a.distance_to(&a).unwrap(), 0);
+
+        let quarters = [
+            DatePeriod::quarter(2019, 3).unwrap(),
+            DatePeriod::quarter(2024, 1).unwrap(),
+        ];
+        assert_eq!(quarters[0].distance_to(&quarters[1]).unwrap(), 18);
+
+        let days = [
+            DatePeriod::daily(2023, 365).unwrap(),
+            DatePeriod::daily(2025, 1).unwrap(),
+        ];
+        assert_eq!(days[0].distance_to(&days[1]).unwrap(), 367);
+        assert!(days[0].distance_to(&a).is_err());
+
+        for kind in [
+            PeriodKind::Year,
+            PeriodKind::Quarter,
+            PeriodKind::Month,
+            PeriodKind::Daily,
+        ] {
+            let first = DatePeriod::from_ordinal(kind, 0).unwrap();
+            assert_eq!(
+                first.get_first_day().unwrap(),
+                NaiveDate::from_ymd_opt(0, 1, 1).unwrap()
+            );
+            let last = DatePeriod::from_ordinal(kind, DatePeriod::max_index(kind)).unwrap();
+            assert_eq!(last.get_year(), DatePeriod::MAX_YEAR);
+            assert!(DatePeriod::from_ordinal(kind, DatePeriod::max_index(kind) + 1).is_err());
+            assert!(DatePeriod::from_ordinal(kind, i64::MIN).is_err());
+
+            let n = first.distance_to(&last).unwrap();
+            assert_eq!(first.checked_step(n), Some(last));
+            // checked_add relies on every distance fitting offset_n's i32
+            assert!(n < i32::MAX as i64);
+            assert_eq!(first.checked_add(n), Some(last));
+            assert_eq!(last.checked_sub(n), Some(first));
+            assert_eq!(first.checked_add(n + 1), None);
+            for ordinal in [1, 1_000, 59_999, 262_142] {
+                let period = DatePeriod::from_ordinal(kind, ordinal).unwrap();
+                assert_eq!(period.ordinal(), ordinal);
+                assert_eq!(first.offset_n(ordinal as i32).unwrap(), period);
+            }
+        }
+    }
+
+    #[test]
+    fn test_operators_match_offset_n() {
+        let periods = [
+            DatePeriod::year(2024).unwrap(),
+            DatePeriod::quarter(2024, 3).unwrap(),
+            DatePeriod::month(2024, 11).unwrap(),
+            DatePeriod::daily(2024, 366).unwrap(),
+        ];
+        for period in periods {
+            for n in [-1000, -13, -1, 0, 1, 4, 12, 365, 1000] {
+                let expected = period.offset_n(n).unwrap();
+                assert_eq!(period + n as i64, expected);
+                assert_eq!(period - (-n) as i64, expected);
+                assert_eq!((period + n as i64) - period, n as i64);
+
+                let mut assigned = period;
+                assigned += n as i64;
+                assert_eq!(assigned, expected);
+                assigned -= n as i64;
+                assert_eq!(assigned, period);
+            }
+            assert_eq!(period.checked_add(i64::MAX), None);
+            assert_eq!(period.checked_add(i64::MIN), None);
+            assert_eq!(period.checked_sub(i64::MIN), None);
+            assert_eq!(period.checked_sub(-2), period.checked_add(2));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the supported years")]
+    fn test_add_past_max_year_panics() {
+        let _ = DatePeriod::year(DatePeriod::MAX_YEAR).unwrap() + 1;
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the supported years")]
+    fn test_sub_before_min_year_panics() {
+        let _ = DatePeriod::month(0, 1).unwrap() - 1;
+    }
+
+    #[test]
+    #[should_panic(expected = "different kinds")]
+    fn test_sub_mixed_kinds_panics() {
+        let _ = DatePeriod::month(2024, 1).unwrap() - DatePeriod::year(2024).unwrap();
+    }
+
+    #[test]
+    fn test_expand_to_and_to_kind() {
+        let kinds = [
+            PeriodKind::Year,
+            PeriodKind::Quarter,
+            PeriodKind::Month,
+            PeriodKind::Daily,
+        ];
+        let periods = [
+            DatePeriod::year(2023).unwrap(),
+            DatePeriod::year(2024).unwrap(),
+            DatePeriod::quarter(2024, 1).unwrap(),
+            DatePeriod::month(2024, 2).unwrap(),
+            DatePeriod::daily(2024, 366).unwrap(),
+            DatePeriod::month(DatePeriod::MAX_YEAR, 12).unwrap(),
+            DatePeriod::quarter(0, 1).unwrap(),
+        ];
+        for period in periods {
+            for kind in kinds.iter().copied().filter(|kind| *kind >= period.kind()) {
+                let children: Vec<_> = period.expand_to(kind).unwrap().collect();
+                assert_eq!(children.len(), period.expand_to(kind).unwrap().len());
+                // Children tile the period exactly, without gaps or overlap
+                assert_eq!(
+                    children[0].get_first_day().unwrap(),
+                    period.get_first_day().unwrap()
+                );
+                assert_eq!(
+                    children.last().unwrap().get_last_day().unwrap(),
+                    period.get_last_day().unwrap()
+                );
+                for pair in children.windows(2) {
+                    assert_eq!(pair[0].succ().unwrap(), pair[1]);
+                }
+                for child in &children {
+                    assert_eq!(child.to_kind(period.kind()).unwrap(), period);
+                }
+                let reversed: Vec<_> = period.expand_to(kind).unwrap().rev().collect();
+                assert!(reversed.iter().rev().eq(children.iter()));
+            }
+            for kind in kinds.iter().copied().filter(|kind| *kind < period.kind()) {
+                assert!(period.expand_to(kind).is_err());
+                assert!(
+                    period
+                        .to_kind(kind)
+                        .unwrap()
+                        .contains_date(period.get_first_day().unwrap())
+                );
+            }
+        }
+
+        assert_eq!(
+            DatePeriod::year(2023)
+                .unwrap()
+                .expand_to(PeriodKind::Daily)
+                .unwrap()
+                .len(),
+            365
+        );
+        let day = DatePeriod::daily(2024, 60).unwrap();
+        assert_eq!(
+            day.to_kind(PeriodKind::Month).unwrap(),
+            DatePeriod::month(2024, 2).unwrap()
+        );
+        assert!(
+            day.to_kind(PeriodKind::Year)
+                .unwrap()
+                .expand_to(PeriodKind::Daily)
+                .unwrap()
+                .any(|d| d == day)
+        );
+    }
+
+    #[test]
+    fn test_period_kind() {
+        for kind in PeriodKind::ALL {
+            assert_eq!(kind.to_string().parse::<PeriodKind>().unwrap(), kind);
+            assert_eq!(kind.short_name().parse::<PeriodKind>().unwrap(), kind);
+            assert_eq!(
+                kind.name().to_lowercase().parse::<PeriodKind>().unwrap(),
+                kind
+            );
+
+            let json = serde_json::to_string(&kind).unwrap();
+            assert_eq!(json, format!("\"{}\"", kind.name()));
+            assert_eq!(serde_json::from_str::<PeriodKind>(&json).unwrap(), kind);
+
+            if let Some(parent) = kind.parent() {
+                assert!(kind.is_finer_than(parent));
+                assert!(parent.is_coarser_than(kind));
+                assert_eq!(parent.child(), Some(kind));
+            }
+            assert!(!kind.is_finer_than(kind));
+            assert!(!kind.is_coarser_than(kind));
+        }
+        assert!(PeriodKind::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!("Day".parse::<PeriodKind>().unwrap(), PeriodKind::Daily);
+        assert!("week".parse::<PeriodKind>().is_err());
+        assert!(serde_json::from_str::<PeriodKind>("\"W\"").is_err());
+
+        let period = DatePeriod::daily(2024, 60).unwrap();
+        assert_eq!(period.kind().name(), period.period_name());
+        assert_eq!(period.kind().short_name(), period.short_name());
+    }
+
+    #[test]
+    fn test_generic_from_date_and_between() {
+        let start = NaiveDate::from_ymd_opt(2023, 11, 20).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        let specific = [
+            DatePeriod::between_date_as_year(start, end).unwrap(),
+            DatePeriod::between_date_as_quarter(start, end).unwrap(),
+            DatePeriod::between_date_as_month(start, end).unwrap(),
+            DatePeriod::between_date_as_daily(start, end).unwrap(),
+        ];
+        for (kind, expected) in PeriodKind::ALL.into_iter().zip(specific) {
+            let periods = DatePeriod::between(start, end, kind).unwrap();
+            assert_eq!(periods, expected);
+            assert_eq!(
+                DatePeriod::between_periods(periods[0], *periods.last().unwrap()).unwrap(),
+                expected
+            );
+            assert_eq!(DatePeriod::from_date(start, kind).unwrap(), periods[0]);
+            assert!(DatePeriod::between(end, start, kind).unwrap().is_empty());
+        }
+        assert_eq!(
+            DatePeriod::between(start, end, PeriodKind::Daily)
+                .unwrap()
+                .len(),
+            107
+        );
+
+        let q = DatePeriod::quarter(2024, 1).unwrap();
+        assert_eq!(DatePeriod::between_periods(q, q).unwrap(), vec![q]);
+        assert!(
+            DatePeriod::between_periods(q.succ().unwrap(), q)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(DatePeriod::between_periods(q, DatePeriod::month(2024, 1).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_weekday_queries() {
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        let weekdays = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        let mut periods =
+            DatePeriod::between(date(2023, 11, 1), date(2024, 3, 31), PeriodKind::Month).unwrap();
+        periods.extend(
+            DatePeriod::between(date(2023, 1, 1), date(2025, 12, 31), PeriodKind::Quarter).unwrap(),
+        );
+        periods.push(DatePeriod::year(2024).unwrap());
+        for period in periods {
+            let first_day = period.get_first_day().unwrap();
+            let last_day = period.get_last_day().unwrap();
+            for weekday in weekdays {
+                let days: Vec<NaiveDate> = period.weekdays(weekday).collect();
+                let expected: Vec<NaiveDate> = first_day
+                    .iter_days()
+                    .take_while(|day| *day <= last_day)
+                    .filter(|day| day.weekday() == weekday)
+                    .collect();
+                assert_eq!(days, expected, "{} {}", period, weekday);
+                assert_eq!(period.nth_weekday(weekday, 1), expected.first().copied());
+                assert_eq!(period.last_weekday(weekday), expected.last().copied());
+                for (i, day) in expected.iter().enumerate() {
+                    assert_eq!(period.nth_weekday(weekday, i as u32 + 1), Some(*day));
+                }
+                assert_eq!(period.nth_weekday(weekday, expected.len() as u32 + 1), None);
+                assert_eq!(period.nth_weekday(weekday, 0), None);
+                assert_eq!(
+                    period.weekdays(weekday).next_back(),
+                    expected.last().copied()
+                );
+            }
+        }
+
+        let day = DatePeriod::daily_from_ymd(2024, 3, 15).unwrap();
+        assert_eq!(day.nth_weekday(Weekday::Fri, 1), Some(date(2024, 3, 15)));
+        assert_eq!(day.nth_weekday(Weekday::Sat, 1), None);
+        assert_eq!(day.weekdays(Weekday::Sat).count(), 0);
+
+        let last_year = DatePeriod::year(DatePeriod::MAX_YEAR).unwrap();
+        assert_eq!(
+            last_year.last_weekday(last_year.get_last_day().unwrap().weekday()),
+            Some(NaiveDate::MAX)
+        );
+    }
 }
@@ -0,0 +1,910 @@
+//! Recurrence rules in the style of iCalendar (RFC 5545) `RRULE`s.
+//!
+//! A [`Recurrence`] such as "every 2nd month on the last day" is evaluated by
+//! walking the [`DatePeriod`]s of its frequency with [`DatePeriod::succ_n`]
+//! and selecting dates within each period. The supported subset of `RRULE`
+//! is `FREQ` (`YEARLY`, `MONTHLY` or `DAILY`), `INTERVAL`, `BYMONTHDAY`,
+//! `BYDAY`, `BYSETPOS`, `COUNT` and `UNTIL`.
+//!
+//! ```rust
+//! use range_date::recurrence::Recurrence;
+//! use chrono::NaiveDate;
+//!
+//! let rule: Recurrence = "FREQ=MONTHLY;INTERVAL=2;BYMONTHDAY=-1".parse().unwrap();
+//! let start = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+//! let end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+//!
+//! let dates: Vec<_> = rule.between(start, end).iter().map(|d| d.to_string()).collect();
+//! assert_eq!(
+//!     dates,
+//!     ["2024-01-31", "2024-03-31", "2024-05-31", "2024-07-31", "2024-09-30", "2024-11-30"]
+//! );
+//! assert_eq!(rule.to_string(), "FREQ=MONTHLY;INTERVAL=2;BYMONTHDAY=-1");
+//! ```
+
+use std::collections::VecDeque;
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::range_type::{DatePeriod, PeriodKind};
+
+/// The `FREQ` of a [`Recurrence`]: the kind of period it steps through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Frequency {
+    Yearly,
+    Monthly,
+    Daily,
+}
+
+impl Frequency {
+    /// Get the [`PeriodKind`] walked for this frequency
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::recurrence::Frequency;
+    /// use range_date::range_type::PeriodKind;
+    ///
+    /// assert_eq!(Frequency::Monthly.kind(), PeriodKind::Month);
+    /// ```
+    pub fn kind(&self) -> PeriodKind {
+        match self {
+            Frequency::Yearly => PeriodKind::Year,
+            Frequency::Monthly => PeriodKind::Month,
+            Frequency::Daily => PeriodKind::Daily,
+        }
+    }
+}
+
+impl std::fmt::Display for Frequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Frequency::Yearly => "YEARLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Daily => "DAILY",
+        };
+        f.write_str(name)
+    }
+}
+
+impl std::str::FromStr for Frequency {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "YEARLY" => Ok(Frequency::Yearly),
+            "MONTHLY" => Ok(Frequency::Monthly),
+            "DAILY" => Ok(Frequency::Daily),
+            _ => Err(anyhow::anyhow!("Unsupported recurrence frequency: {}", s)),
+        }
+    }
+}
+
+/// One `BYDAY` entry: a weekday, optionally restricted to its `nth`
+/// occurrence within the month or year
+///
+/// The textual form is the weekday's two-letter code with an optional
+/// signed ordinal, e.g. `MO`, `2TU` or `-1FR` (the last Friday).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ByDay {
+    weekday: Weekday,
+    nth: Option<i32>,
+}
+
+impl ByDay {
+    /// Select every `weekday` within the period
+    pub fn every(weekday: Weekday) -> Self {
+        ByDay { weekday, nth: None }
+    }
+
+    /// Select the `nth` `weekday` within the period, counting from the end
+    /// when `nth` is negative
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `nth` is zero or outside `-53..=53`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::recurrence::ByDay;
+    /// use chrono::Weekday;
+    ///
+    /// assert_eq!(ByDay::nth(-1, Weekday::Fri).unwrap().to_string(), "-1FR");
+    /// assert!(ByDay::nth(0, Weekday::Fri).is_err());
+    /// ```
+    pub fn nth(nth: i32, weekday: Weekday) -> anyhow::Result<Self> {
+        if nth == 0 || nth.unsigned_abs() > 53 {
+            return Err(anyhow::anyhow!(
+                "Weekday ordinal must be in -53..=53 and not 0, got {}",
+                nth
+            ));
+        }
+        Ok(ByDay {
+            weekday,
+            nth: Some(nth),
+        })
+    }
+
+    /// Get the weekday
+    pub fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// Get the ordinal, `None` if every occurrence is selected
+    pub fn get_nth(&self) -> Option<i32> {
+        self.nth
+    }
+
+    /// Dates within `period` selected by this entry
+    fn dates_in(&self, period: DatePeriod) -> Vec<NaiveDate> {
+        let mut dates = period.weekdays(self.weekday);
+        match self.nth {
+            None => dates.collect(),
+            Some(nth) if nth > 0 => dates.nth(nth as usize - 1).into_iter().collect(),
+            Some(nth) => dates
+                .nth_back(nth.unsigned_abs() as usize - 1)
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for ByDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(nth) = self.nth {
+            write!(f, "{}", nth)?;
+        }
+        f.write_str(weekday_code(self.weekday))
+    }
+}
+
+impl std::str::FromStr for ByDay {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || anyhow::anyhow!("Invalid BYDAY value: {}", s);
+        let split = s.len().checked_sub(2).ok_or_else(invalid)?;
+        let (nth, code) = (s.get(..split).ok_or_else(invalid)?, &s[split..]);
+        let weekday = match code.to_ascii_uppercase().as_str() {
+            "MO" => Weekday::Mon,
+            "TU" => Weekday::Tue,
+            "WE" => Weekday::Wed,
+            "TH" => Weekday::Thu,
+            "FR" => Weekday::Fri,
+            "SA" => Weekday::Sat,
+            "SU" => Weekday::Sun,
+            _ => return Err(invalid()),
+        };
+        if nth.is_empty() {
+            return Ok(ByDay::every(weekday));
+        }
+        ByDay::nth(nth.parse::<i32>().map_err(|_| invalid())?, weekday)
+    }
+}
+
+/// Two-letter iCalendar code of a weekday
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+/// The date for `BYMONTHDAY` value `day` within `month`, counting from the
+/// end of the month when `day` is negative
+fn month_day(month: DatePeriod, day: i32) -> Option<NaiveDate> {
+    let first_day = month.get_first_day().ok()?;
+    let length = month.get_last_day().ok()?.day() as i32;
+    let day = if day > 0 { day } else { length + 1 + day };
+    (1..=length)
+        .contains(&day)
+        .then(|| first_day + Duration::days(day as i64 - 1))
+}
+
+/// A recurrence rule evaluated over [`DatePeriod`]s
+///
+/// Starting from the period of the rule's [`Frequency`] that contains the
+/// start date, every `INTERVAL`-th period is expanded into its candidate
+/// dates:
+///
+/// - `BYMONTHDAY` selects days of the month (negative values count from the
+///   end), in every month of the period
+/// - `BYDAY` selects weekdays, or the nth weekday of the month (`MONTHLY`) or
+///   year (`YEARLY`); together with `BYMONTHDAY` it narrows that selection
+/// - without either, the start date's day of month (`MONTHLY`) or month and
+///   day (`YEARLY`) is used, skipping periods where it does not exist
+///
+/// `BYSETPOS` then picks positions from each period's sorted candidates.
+/// Dates before the start date are dropped, and the rule ends after `COUNT`
+/// occurrences or on `UNTIL` (inclusive).
+///
+/// The textual form is the `RRULE` value, e.g.
+/// `FREQ=MONTHLY;INTERVAL=3;BYMONTHDAY=15`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Recurrence {
+    frequency: Frequency,
+    interval: u32,
+    month_days: Vec<i32>,
+    weekdays: Vec<ByDay>,
+    set_positions: Vec<i32>,
+    count: Option<u32>,
+    until: Option<NaiveDate>,
+}
+
+impl Recurrence {
+    /// Create a rule recurring every period of `frequency`
+    ///
+    /// The rule is refined with the `with_*` methods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::recurrence::{Frequency, Recurrence};
+    ///
+    /// // Quarterly on the 15th
+    /// let rule = Recurrence::new(Frequency::Monthly)
+    ///     .with_interval(3)
+    ///     .and_then(|rule| rule.with_month_days(&[15]))
+    ///     .unwrap();
+    /// assert_eq!(rule.to_string(), "FREQ=MONTHLY;INTERVAL=3;BYMONTHDAY=15");
+    /// ```
+    pub fn new(frequency: Frequency) -> Self {
+        Recurrence {
+            frequency,
+            interval: 1,
+            month_days: vec![],
+            weekdays: vec![],
+            set_positions: vec![],
+            count: None,
+            until: None,
+        }
+    }
+
+    /// Recur every `interval`-th period (`INTERVAL`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `interval` is zero.
+    pub fn with_interval(mut self, interval: u32) -> anyhow::Result<Self> {
+        if interval == 0 {
+            return Err(anyhow::anyhow!("Recurrence interval must be at least 1"));
+        }
+        self.interval = interval;
+        Ok(self)
+    }
+
+    /// Select days of the month (`BYMONTHDAY`), `-1` being the last day
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a day is zero or outside `-31..=31`.
+    pub fn with_month_days(mut self, days: &[i32]) -> anyhow::Result<Self> {
+        if let Some(day) = days
+            .iter()
+            .find(|day| **day == 0 || day.unsigned_abs() > 31)
+        {
+            return Err(anyhow::anyhow!(
+                "Day of month must be in -31..=31 and not 0, got {}",
+                day
+            ));
+        }
+        self.month_days = days.to_vec();
+        Ok(self)
+    }
+
+    /// Select weekdays (`BYDAY`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an entry has an ordinal and the frequency is
+    /// [`Frequency::Daily`], or the ordinal exceeds the weeks of a month
+    /// (`-5..=5`) for [`Frequency::Monthly`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::recurrence::{ByDay, Frequency, Recurrence};
+    /// use chrono::Weekday;
+    ///
+    /// let second_tuesday = ByDay::nth(2, Weekday::Tue).unwrap();
+    /// assert!(Recurrence::new(Frequency::Monthly).with_weekdays(&[second_tuesday]).is_ok());
+    /// assert!(Recurrence::new(Frequency::Daily).with_weekdays(&[second_tuesday]).is_err());
+    /// ```
+    pub fn with_weekdays(mut self, weekdays: &[ByDay]) -> anyhow::Result<Self> {
+        let max_nth = match self.frequency {
+            Frequency::Yearly => 53,
+            Frequency::Monthly => 5,
+            Frequency::Daily => 0,
+        };
+        if let Some(by_day) = weekdays
+            .iter()
+            .find(|by_day| by_day.nth.is_some_and(|nth| nth.unsigned_abs() > max_nth))
+        {
+            return Err(anyhow::anyhow!(
+                "Weekday {} is not valid for {} recurrences",
+                by_day,
+                self.frequency
+            ));
+        }
+        self.weekdays = weekdays.to_vec();
+        Ok(self)
+    }
+
+    /// Keep only the given positions of each period's candidates
+    /// (`BYSETPOS`), `-1` being the last
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a position is zero or outside `-366..=366`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::recurrence::{ByDay, Frequency, Recurrence};
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// // Last weekday of every month
+    /// let workdays = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]
+    ///     .map(ByDay::every);
+    /// let rule = Recurrence::new(Frequency::Monthly)
+    ///     .with_weekdays(&workdays)
+    ///     .and_then(|rule| rule.with_set_positions(&[-1]))
+    ///     .unwrap();
+    /// let start = NaiveDate::from_ymd_opt(2024, 8, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 9, 30).unwrap();
+    /// assert_eq!(
+    ///     rule.between(start, end),
+    ///     [NaiveDate::from_ymd_opt(2024, 8, 30).unwrap(), NaiveDate::from_ymd_opt(2024, 9, 30).unwrap()]
+    /// );
+    /// ```
+    pub fn with_set_positions(mut self, positions: &[i32]) -> anyhow::Result<Self> {
+        if let Some(position) = positions
+            .iter()
+            .find(|position| **position == 0 || position.unsigned_abs() > 366)
+        {
+            return Err(anyhow::anyhow!(
+                "Set position must be in -366..=366 and not 0, got {}",
+                position
+            ));
+        }
+        self.set_positions = positions.to_vec();
+        Ok(self)
+    }
+
+    /// End the rule after `count` occurrences (`COUNT`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `count` is zero or the rule already has an
+    /// `UNTIL` date.
+    pub fn with_count(mut self, count: u32) -> anyhow::Result<Self> {
+        if count == 0 {
+            return Err(anyhow::anyhow!("Recurrence count must be at least 1"));
+        }
+        if self.until.is_some() {
+            return Err(anyhow::anyhow!(
+                "Recurrence cannot have both COUNT and UNTIL"
+            ));
+        }
+        self.count = Some(count);
+        Ok(self)
+    }
+
+    /// End the rule on `until`, inclusive (`UNTIL`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rule already has a `COUNT`.
+    pub fn with_until(mut self, until: NaiveDate) -> anyhow::Result<Self> {
+        if self.count.is_some() {
+            return Err(anyhow::anyhow!(
+                "Recurrence cannot have both COUNT and UNTIL"
+            ));
+        }
+        self.until = Some(until);
+        Ok(self)
+    }
+
+    /// Get the frequency
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// Get the number of periods between recurrences
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Get the selected days of the month
+    pub fn month_days(&self) -> &[i32] {
+        &self.month_days
+    }
+
+    /// Get the selected weekdays
+    pub fn weekdays(&self) -> &[ByDay] {
+        &self.weekdays
+    }
+
+    /// Get the selected positions within each period
+    pub fn set_positions(&self) -> &[i32] {
+        &self.set_positions
+    }
+
+    /// Get the maximum number of occurrences
+    pub fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    /// Get the last date an occurrence may fall on
+    pub fn until(&self) -> Option<NaiveDate> {
+        self.until
+    }
+
+    /// Iterate over the occurrences on or after `start`, in order
+    ///
+    /// Without `COUNT` or `UNTIL` the iterator only ends at
+    /// [`DatePeriod::MAX_YEAR`]; use [`Recurrence::between`] or bound it
+    /// with `take` / `take_while`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::recurrence::Recurrence;
+    /// use chrono::NaiveDate;
+    ///
+    /// // Second Tuesday of the month, three times
+    /// let rule: Recurrence = "FREQ=MONTHLY;BYDAY=2TU;COUNT=3".parse().unwrap();
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let dates: Vec<_> = rule.occurrences(start).map(|d| d.to_string()).collect();
+    /// assert_eq!(dates, ["2024-01-09", "2024-02-13", "2024-03-12"]);
+    /// ```
+    pub fn occurrences(&self, start: NaiveDate) -> Occurrences<'_> {
+        Occurrences {
+            rule: self,
+            start,
+            end: self.until,
+            period: DatePeriod::from_date(start, self.frequency.kind()).ok(),
+            pending: VecDeque::new(),
+            emitted: 0,
+        }
+    }
+
+    /// Get all occurrences from `start` through `end` (inclusive)
+    /// Returns an empty vector if start > end
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::recurrence::Recurrence;
+    /// use chrono::NaiveDate;
+    ///
+    /// let rule: Recurrence = "FREQ=DAILY;INTERVAL=10".parse().unwrap();
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    /// assert_eq!(rule.between(start, end).len(), 4);
+    /// assert!(rule.between(end, start).is_empty());
+    /// ```
+    pub fn between(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        let mut occurrences = self.occurrences(start);
+        occurrences.end = Some(occurrences.end.map_or(end, |until| until.min(end)));
+        occurrences.collect()
+    }
+
+    /// Sorted candidate dates of `period`, after `BYSETPOS`
+    fn candidates(&self, period: DatePeriod, start: NaiveDate) -> Vec<NaiveDate> {
+        let months: Vec<DatePeriod> = match self.frequency {
+            Frequency::Daily => period.to_kind(PeriodKind::Month).into_iter().collect(),
+            _ => period
+                .expand_to(PeriodKind::Month)
+                .map(Iterator::collect)
+                .unwrap_or_default(),
+        };
+        let by_weekday = || {
+            let mut dates: Vec<NaiveDate> = self
+                .weekdays
+                .iter()
+                .flat_map(|by_day| by_day.dates_in(period))
+                .collect();
+            dates.sort();
+            dates
+        };
+
+        let mut dates: Vec<NaiveDate> = if !self.month_days.is_empty() {
+            let mut dates: Vec<NaiveDate> = months
+                .iter()
+                .flat_map(|month| {
+                    self.month_days
+                        .iter()
+                        .filter_map(|day| month_day(*month, *day))
+                })
+                .collect();
+            if !self.weekdays.is_empty() {
+                let selected = by_weekday();
+                dates.retain(|date| selected.binary_search(date).is_ok());
+            }
+            dates
+        } else if !self.weekdays.is_empty() {
+            by_weekday()
+        } else if self.frequency == Frequency::Daily {
+            period.to_date().into_iter().collect()
+        } else {
+            let day = start.day();
+            let month = (self.frequency == Frequency::Yearly).then_some(start.month());
+            months
+                .iter()
+                .filter(|m| month.is_none_or(|month| m.get_month() == Some(month)))
+                .filter_map(|m| month_day(*m, day as i32))
+                .collect()
+        };
+        if self.frequency == Frequency::Daily {
+            let day = period.to_date();
+            dates.retain(|date| Some(*date) == day);
+        }
+        dates.sort();
+        dates.dedup();
+
+        if self.set_positions.is_empty() {
+            return dates;
+        }
+        let mut selected: Vec<NaiveDate> = self
+            .set_positions
+            .iter()
+            .filter_map(|position| {
+                let index = if *position > 0 {
+                    *position as usize - 1
+                } else {
+                    dates.len().checked_sub(position.unsigned_abs() as usize)?
+                };
+                dates.get(index).copied()
+            })
+            .collect();
+        selected.sort();
+        selected.dedup();
+        selected
+    }
+}
+
+impl std::fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn join<T: ToString>(values: &[T]) -> String {
+            values
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",")
+        }
+
+        write!(f, "FREQ={}", self.frequency)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.month_days.is_empty() {
+            write!(f, ";BYMONTHDAY={}", join(&self.month_days))?;
+        }
+        if !self.weekdays.is_empty() {
+            write!(f, ";BYDAY={}", join(&self.weekdays))?;
+        }
+        if !self.set_positions.is_empty() {
+            write!(f, ";BYSETPOS={}", join(&self.set_positions))?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%d"))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Recurrence {
+    type Err = anyhow::Error;
+
+    /// Parse an `RRULE` value, with or without the `RRULE:` prefix
+    ///
+    /// `UNTIL` may be a date (`20241231`) or a date-time
+    /// (`20241231T235959Z`), of which only the date is used.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn list<T: std::str::FromStr>(value: &str) -> anyhow::Result<Vec<T>> {
+            value
+                .split(',')
+                .map(|item| {
+                    item.trim()
+                        .parse::<T>()
+                        .map_err(|_| anyhow::anyhow!("Invalid RRULE list item: {}", item))
+                })
+                .collect()
+        }
+
+        let body = s.trim();
+        let body = match body.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => &body[6..],
+            _ => body,
+        };
+        let mut parts = std::collections::HashMap::new();
+        for part in body.split(';').filter(|part| !part.is_empty()) {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| anyhow::anyhow!("Invalid RRULE part: {}", part))?;
+            let name = name.trim().to_ascii_uppercase();
+            if parts.insert(name.clone(), value.trim()).is_some() {
+                return Err(anyhow::anyhow!("Duplicate RRULE part: {}", name));
+            }
+        }
+
+        let frequency = parts
+            .remove("FREQ")
+            .ok_or_else(|| anyhow::anyhow!("RRULE is missing FREQ: {}", s))?;
+        let mut rule = Recurrence::new(frequency.parse()?);
+        if let Some(interval) = parts.remove("INTERVAL") {
+            let interval = interval
+                .parse::<u32>()
+                .map_err(|_| anyhow::anyhow!("Invalid INTERVAL: {}", interval))?;
+            rule = rule.with_interval(interval)?;
+        }
+        if let Some(days) = parts.remove("BYMONTHDAY") {
+            rule = rule.with_month_days(&list::<i32>(days)?)?;
+        }
+        if let Some(weekdays) = parts.remove("BYDAY") {
+            rule = rule.with_weekdays(&list::<ByDay>(weekdays)?)?;
+        }
+        if let Some(positions) = parts.remove("BYSETPOS") {
+            rule = rule.with_set_positions(&list::<i32>(positions)?)?;
+        }
+        if let Some(count) = parts.remove("COUNT") {
+            let count = count
+                .parse::<u32>()
+                .map_err(|_| anyhow::anyhow!("Invalid COUNT: {}", count))?;
+            rule = rule.with_count(count)?;
+        }
+        if let Some(until) = parts.remove("UNTIL") {
+            let date = until.split_once('T').map_or(until, |(date, _)| date);
+            let date = NaiveDate::parse_from_str(date, "%Y%m%d")
+                .map_err(|_| anyhow::anyhow!("Invalid UNTIL: {}", until))?;
+            rule = rule.with_until(date)?;
+        }
+        if let Some(name) = parts.keys().min() {
+            return Err(anyhow::anyhow!("Unsupported RRULE part: {}", name));
+        }
+        Ok(rule)
+    }
+}
+
+impl Serialize for Recurrence {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Recurrence {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Recurrence, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use std::str::FromStr;
+        let s = String::deserialize(deserializer)?;
+        Recurrence::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Iterator over the occurrences of a [`Recurrence`], created by
+/// [`Recurrence::occurrences`]
+#[derive(Debug, Clone)]
+pub struct Occurrences<'a> {
+    rule: &'a Recurrence,
+    start: NaiveDate,
+    end: Option<NaiveDate>,
+    period: Option<DatePeriod>,
+    pending: VecDeque<NaiveDate>,
+    emitted: u32,
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<NaiveDate> {
+        loop {
+            if self.rule.count.is_some_and(|count| self.emitted >= count) {
+                return None;
+            }
+            if let Some(date) = self.pending.pop_front() {
+                if self.end.is_some_and(|end| date > end) {
+                    self.period = None;
+                    self.pending.clear();
+                    return None;
+                }
+                if date < self.start {
+                    continue;
+                }
+                self.emitted += 1;
+                return Some(date);
+            }
+            let period = self.period?;
+            let first_day = period.get_first_day().ok();
+            if first_day.is_none() || self.end.is_some_and(|end| first_day > Some(end)) {
+                self.period = None;
+                return None;
+            }
+            self.pending = self.rule.candidates(period, self.start).into();
+            self.period = period.succ_n(self.rule.interval).ok();
+        }
+    }
+}
+
+impl std::iter::FusedIterator for Occurrences<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn dates(rule: &str, start: NaiveDate, end: NaiveDate) -> Vec<String> {
+        rule.parse::<Recurrence>()
+            .unwrap()
+            .between(start, end)
+            .iter()
+            .map(|d| d.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_monthly_rules() {
+        let start = date(2024, 1, 31);
+        let end = date(2024, 6, 30);
+        // Months without a 31st are skipped
+        assert_eq!(
+            dates("FREQ=MONTHLY", start, end),
+            ["2024-01-31", "2024-03-31", "2024-05-31"]
+        );
+        assert_eq!(
+            dates(
+                "FREQ=MONTHLY;INTERVAL=3;BYMONTHDAY=15",
+                date(2024, 1, 1),
+                end
+            ),
+            ["2024-01-15", "2024-04-15"]
+        );
+        assert_eq!(
+            dates(
+                "FREQ=MONTHLY;BYDAY=-1FR,1MO",
+                date(2024, 2, 1),
+                date(2024, 3, 31)
+            ),
+            ["2024-02-05", "2024-02-23", "2024-03-04", "2024-03-29"]
+        );
+        // Friday the 13th
+        assert_eq!(
+            dates(
+                "FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13",
+                date(2024, 1, 1),
+                date(2024, 12, 31)
+            ),
+            ["2024-09-13", "2024-12-13"]
+        );
+        // Second-to-last business day
+        assert_eq!(
+            dates(
+                "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-2",
+                date(2024, 3, 1),
+                date(2024, 4, 30)
+            ),
+            ["2024-03-28", "2024-04-29"]
+        );
+    }
+
+    #[test]
+    fn test_yearly_and_daily_rules() {
+        assert_eq!(
+            dates("FREQ=YEARLY", date(2024, 2, 29), date(2032, 12, 31)),
+            ["2024-02-29", "2028-02-29", "2032-02-29"]
+        );
+        assert_eq!(
+            dates(
+                "FREQ=YEARLY;BYMONTHDAY=1;BYSETPOS=1,-1",
+                date(2024, 1, 1),
+                date(2025, 6, 1)
+            ),
+            ["2024-01-01", "2024-12-01", "2025-01-01"]
+        );
+        assert_eq!(
+            dates(
+                "FREQ=YEARLY;BYDAY=20MO",
+                date(2024, 1, 1),
+                date(2024, 12, 31)
+            ),
+            ["2024-05-13"]
+        );
+        assert_eq!(
+            dates(
+                "FREQ=DAILY;BYDAY=SA,SU",
+                date(2024, 3, 1),
+                date(2024, 3, 10)
+            ),
+            ["2024-03-02", "2024-03-03", "2024-03-09", "2024-03-10"]
+        );
+        assert_eq!(
+            dates(
+                "FREQ=DAILY;INTERVAL=2;BYMONTHDAY=-1,1",
+                date(2024, 1, 30),
+                date(2024, 3, 2)
+            ),
+            ["2024-02-01", "2024-02-29"]
+        );
+    }
+
+    #[test]
+    fn test_count_until_and_start() {
+        let rule: Recurrence = "FREQ=MONTHLY;BYMONTHDAY=1,15;COUNT=3".parse().unwrap();
+        let occurrences: Vec<_> = rule.occurrences(date(2024, 1, 10)).collect();
+        assert_eq!(
+            occurrences,
+            [date(2024, 1, 15), date(2024, 2, 1), date(2024, 2, 15)]
+        );
+        // COUNT applies before the horizon cuts the sequence
+        assert_eq!(rule.between(date(2024, 1, 10), date(2024, 1, 31)).len(), 1);
+
+        let rule: Recurrence = "FREQ=DAILY;UNTIL=20240105T120000Z".parse().unwrap();
+        assert_eq!(rule.occurrences(date(2024, 1, 1)).count(), 5);
+        assert_eq!(rule.between(date(2024, 1, 1), date(2024, 1, 3)).len(), 3);
+        assert_eq!(rule.occurrences(date(2024, 2, 1)).next(), None);
+
+        let last = DatePeriod::MAX_YEAR as i32;
+        let rule: Recurrence = "FREQ=YEARLY".parse().unwrap();
+        assert_eq!(rule.occurrences(date(last - 1, 6, 1)).count(), 2);
+    }
+
+    #[test]
+    fn test_text_round_trip_and_serde() {
+        let rule = Recurrence::new(Frequency::Monthly)
+            .with_interval(2)
+            .and_then(|r| r.with_weekdays(&[ByDay::nth(-1, Weekday::Fri).unwrap()]))
+            .and_then(|r| r.with_until(date(2024, 12, 31)))
+            .unwrap();
+        assert_eq!(
+            rule.to_string(),
+            "FREQ=MONTHLY;INTERVAL=2;BYDAY=-1FR;UNTIL=20241231"
+        );
+        assert_eq!(rule.to_string().parse::<Recurrence>().unwrap(), rule);
+        assert_eq!(
+            "rrule:freq=monthly;until=20241231;byday=-1fr;interval=2"
+                .parse::<Recurrence>()
+                .unwrap(),
+            rule
+        );
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(serde_json::from_str::<Recurrence>(&json).unwrap(), rule);
+
+        for invalid in [
+            "",
+            "INTERVAL=2",
+            "FREQ=WEEKLY",
+            "FREQ=DAILY;INTERVAL=0",
+            "FREQ=DAILY;BYDAY=1MO",
+            "FREQ=MONTHLY;BYDAY=6MO",
+            "FREQ=MONTHLY;BYDAY=XX",
+            "FREQ=MONTHLY;BYMONTHDAY=0",
+            "FREQ=MONTHLY;BYMONTHDAY=32",
+            "FREQ=MONTHLY;BYSETPOS=0",
+            "FREQ=MONTHLY;COUNT=0",
+            "FREQ=MONTHLY;COUNT=2;UNTIL=20241231",
+            "FREQ=MONTHLY;FREQ=DAILY",
+            "FREQ=MONTHLY;BYMONTH=1",
+            "FREQ=MONTHLY;UNTIL=2024-12-31",
+        ] {
+            assert!(invalid.parse::<Recurrence>().is_err(), "{}", invalid);
+        }
+    }
+}
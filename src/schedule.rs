@@ -0,0 +1,473 @@
+//! Cron-like schedules that trigger once per closed period.
+//!
+//! Reporting jobs run "for every closed month" or "on the 2nd business day
+//! after quarter end". A [`Schedule`] is parsed from such a phrase and
+//! evaluates to `(trigger_date, period)` pairs, where `period` is the
+//! [`DatePeriod`] that had just closed on the trigger date.
+//!
+//! ```rust
+//! use range_date::schedule::Schedule;
+//! use chrono::NaiveDate;
+//!
+//! let schedule: Schedule = "on the 2nd business day after quarter end".parse().unwrap();
+//! let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+//! let end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+//!
+//! let runs: Vec<_> = schedule
+//!     .between(start, end)
+//!     .unwrap()
+//!     .into_iter()
+//!     .map(|(date, period)| format!("{} {}", date, period))
+//!     .collect();
+//! assert_eq!(
+//!     runs,
+//!     ["2024-01-02 2023Q4", "2024-04-02 2024Q1", "2024-07-02 2024Q2", "2024-10-02 2024Q3"]
+//! );
+//! ```
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::range_type::{DatePeriod, PeriodKind};
+
+/// What the offset of a [`Schedule`] counts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OffsetUnit {
+    /// Calendar days
+    Day,
+    /// Monday to Friday; holidays are not taken into account
+    BusinessDay,
+}
+
+/// Triggers the `offset`-th day (or business day) after the end of every
+/// period of `kind`
+///
+/// The textual form is `every closed <kind>` for the day after each period
+/// ends, and `<offset> [business] day after <kind> end` otherwise, e.g.
+/// `2nd business day after quarter end`. When parsing, offsets may be
+/// written with or without an ordinal suffix (`2` or `2nd`), `days` is
+/// accepted for `day`, a leading `on the` is ignored and `<kind>` is any
+/// name accepted by [`PeriodKind`]'s `FromStr`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Schedule {
+    kind: PeriodKind,
+    offset: u32,
+    unit: OffsetUnit,
+}
+
+impl Schedule {
+    /// Create a schedule triggering `offset` units after each period of
+    /// `kind` ends
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `offset` is zero, as the period would not have
+    /// closed yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::PeriodKind;
+    /// use range_date::schedule::{OffsetUnit, Schedule};
+    ///
+    /// let schedule = Schedule::new(PeriodKind::Month, 3, OffsetUnit::BusinessDay).unwrap();
+    /// assert_eq!(schedule.to_string(), "3rd business day after month end");
+    /// assert!(Schedule::new(PeriodKind::Month, 0, OffsetUnit::Day).is_err());
+    /// ```
+    pub fn new(kind: PeriodKind, offset: u32, unit: OffsetUnit) -> anyhow::Result<Self> {
+        if offset == 0 {
+            return Err(anyhow::anyhow!("Schedule offset must be at least 1"));
+        }
+        Ok(Schedule { kind, offset, unit })
+    }
+
+    /// Create a schedule triggering on the first day after each period of
+    /// `kind` ends
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::PeriodKind;
+    /// use range_date::schedule::Schedule;
+    ///
+    /// let schedule = Schedule::closed(PeriodKind::Month);
+    /// assert_eq!(schedule.to_string(), "every closed month");
+    /// ```
+    pub fn closed(kind: PeriodKind) -> Self {
+        Schedule {
+            kind,
+            offset: 1,
+            unit: OffsetUnit::Day,
+        }
+    }
+
+    /// Get the kind of period that is reported on
+    pub fn kind(&self) -> PeriodKind {
+        self.kind
+    }
+
+    /// Get the number of days or business days after the period end
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Get what the offset counts
+    pub fn unit(&self) -> OffsetUnit {
+        self.unit
+    }
+
+    /// Get the date on which the schedule triggers for `period`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `period` is not of the schedule's kind, or the
+    /// trigger date is not representable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    /// use range_date::schedule::Schedule;
+    /// use chrono::NaiveDate;
+    ///
+    /// let schedule: Schedule = "5 days after month end".parse().unwrap();
+    /// let february = DatePeriod::month(2024, 2).unwrap();
+    /// assert_eq!(
+    ///     schedule.trigger_date(february).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 3, 5).unwrap()
+    /// );
+    /// assert!(schedule.trigger_date(DatePeriod::year(2024).unwrap()).is_err());
+    /// ```
+    pub fn trigger_date(&self, period: DatePeriod) -> anyhow::Result<NaiveDate> {
+        if period.kind() != self.kind {
+            return Err(anyhow::anyhow!(
+                "Schedule for {} periods cannot trigger for {}",
+                self.kind,
+                period
+            ));
+        }
+        let last_day = period.get_last_day()?;
+        let out_of_range = || anyhow::anyhow!("Trigger date for {} is out of range", period);
+        match self.unit {
+            OffsetUnit::Day => last_day
+                .checked_add_days(Days::new(self.offset as u64))
+                .ok_or_else(out_of_range),
+            OffsetUnit::BusinessDay => {
+                let mut date = last_day;
+                let mut remaining = self.offset;
+                while remaining > 0 {
+                    date = date.succ_opt().ok_or_else(out_of_range)?;
+                    if !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+                        remaining -= 1;
+                    }
+                }
+                Ok(date)
+            }
+        }
+    }
+
+    /// Get the periods that the schedule reports on when triggered on
+    /// `date`, in order
+    ///
+    /// Several periods close on the same date when their trigger dates
+    /// coincide, e.g. Friday, Saturday and Sunday all trigger on Monday for
+    /// "1st business day after day end". Returns an empty vector if the
+    /// schedule does not trigger on `date`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::range_type::DatePeriod;
+    /// use range_date::schedule::Schedule;
+    /// use chrono::NaiveDate;
+    ///
+    /// let schedule: Schedule = "every closed month".parse().unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    /// assert_eq!(schedule.closed_periods(date), [DatePeriod::month(2024, 2).unwrap()]);
+    /// assert!(schedule.closed_periods(date.succ_opt().unwrap()).is_empty());
+    /// ```
+    pub fn closed_periods(&self, date: NaiveDate) -> Vec<DatePeriod> {
+        self.between(date, date)
+            .map(|runs| runs.into_iter().map(|(_, period)| period).collect())
+            .unwrap_or_default()
+    }
+
+    /// Get all `(trigger_date, period)` pairs with a trigger date between
+    /// `start` and `end` (inclusive), in order
+    /// Returns an empty vector if start > end
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `start` lies outside the years [`DatePeriod`]
+    /// supports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::schedule::Schedule;
+    /// use chrono::NaiveDate;
+    ///
+    /// let schedule: Schedule = "every closed month".parse().unwrap();
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+    /// let runs = schedule.between(start, end).unwrap();
+    /// assert_eq!(runs.len(), 3);
+    /// assert_eq!(runs[0].0, NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+    /// assert_eq!(runs[0].1.to_string(), "2024M1");
+    /// ```
+    pub fn between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> anyhow::Result<Vec<(NaiveDate, DatePeriod)>> {
+        if start > end {
+            return Ok(vec![]);
+        }
+        // The period containing `start` triggers after `start`; step back
+        // to the earliest period still triggering on or after it
+        let mut period = DatePeriod::from_date(start, self.kind)?;
+        while let Ok(previous) = period.pred() {
+            if self.trigger_date(previous)? < start {
+                break;
+            }
+            period = previous;
+        }
+
+        let mut runs = vec![];
+        while let Ok(trigger) = self.trigger_date(period) {
+            if trigger > end {
+                break;
+            }
+            runs.push((trigger, period));
+            match period.succ() {
+                Ok(next) => period = next,
+                Err(_) => break,
+            }
+        }
+        Ok(runs)
+    }
+}
+
+/// Lower-case word for a kind, as used in schedule phrases
+fn kind_word(kind: PeriodKind) -> &'static str {
+    match kind {
+        PeriodKind::Year => "year",
+        PeriodKind::Quarter => "quarter",
+        PeriodKind::Month => "month",
+        PeriodKind::Daily => "day",
+    }
+}
+
+impl std::fmt::Display for Schedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if *self == Schedule::closed(self.kind) {
+            return write!(f, "every closed {}", kind_word(self.kind));
+        }
+        let suffix = match (self.offset % 10, self.offset % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        };
+        let unit = match self.unit {
+            OffsetUnit::Day => "day",
+            OffsetUnit::BusinessDay => "business day",
+        };
+        write!(
+            f,
+            "{}{} {} after {} end",
+            self.offset,
+            suffix,
+            unit,
+            kind_word(self.kind)
+        )
+    }
+}
+
+impl std::str::FromStr for Schedule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || anyhow::anyhow!("Invalid schedule: {}", s);
+        let lower = s.to_ascii_lowercase();
+        let mut words: Vec<&str> = lower.split_whitespace().collect();
+        if words.starts_with(&["on", "the"]) {
+            words.drain(..2);
+        }
+
+        let (offset, unit, kind) = match words.as_slice() {
+            ["every", "closed", kind] => return Ok(Schedule::closed(kind.parse()?)),
+            [offset, "day" | "days", "after", kind, "end"] => (*offset, OffsetUnit::Day, *kind),
+            [offset, "business", "day" | "days", "after", kind, "end"] => {
+                (*offset, OffsetUnit::BusinessDay, *kind)
+            }
+            _ => return Err(invalid()),
+        };
+        let digits = ["st", "nd", "rd", "th"]
+            .iter()
+            .find_map(|suffix| offset.strip_suffix(suffix))
+            .unwrap_or(offset);
+        let offset = digits.parse::<u32>().map_err(|_| invalid())?;
+        Schedule::new(kind.parse()?, offset, unit)
+    }
+}
+
+impl Serialize for Schedule {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Schedule {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Schedule, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use std::str::FromStr;
+        let s = String::deserialize(deserializer)?;
+        Schedule::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_every_closed_period() {
+        let schedule: Schedule = "every closed month".parse().unwrap();
+        let runs = schedule
+            .between(date(2024, 1, 1), date(2024, 12, 31))
+            .unwrap();
+        assert_eq!(runs.len(), 12);
+        assert_eq!(
+            runs[0],
+            (date(2024, 1, 1), DatePeriod::month(2023, 12).unwrap())
+        );
+        assert_eq!(
+            runs[11],
+            (date(2024, 12, 1), DatePeriod::month(2024, 11).unwrap())
+        );
+        for (trigger, period) in &runs {
+            assert_eq!(
+                DatePeriod::from_date_as_month(*trigger)
+                    .unwrap()
+                    .pred()
+                    .unwrap(),
+                *period
+            );
+            assert_eq!(schedule.closed_periods(*trigger), [*period]);
+        }
+
+        let yearly = Schedule::closed(PeriodKind::Year);
+        assert_eq!(
+            yearly.between(date(2024, 1, 1), date(2026, 1, 1)).unwrap(),
+            [
+                (date(2024, 1, 1), DatePeriod::year(2023).unwrap()),
+                (date(2025, 1, 1), DatePeriod::year(2024).unwrap()),
+                (date(2026, 1, 1), DatePeriod::year(2025).unwrap()),
+            ]
+        );
+        assert!(
+            yearly
+                .between(date(2026, 1, 1), date(2024, 1, 1))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_business_day_offsets() {
+        let schedule: Schedule = "2nd business day after quarter end".parse().unwrap();
+        // 2024Q4 ends on a Tuesday; holidays are not skipped
+        assert_eq!(
+            schedule
+                .trigger_date(DatePeriod::quarter(2024, 4).unwrap())
+                .unwrap(),
+            date(2025, 1, 2)
+        );
+        assert!(schedule.closed_periods(date(2024, 4, 1)).is_empty());
+        assert_eq!(
+            schedule.closed_periods(date(2024, 4, 2)),
+            [DatePeriod::quarter(2024, 1).unwrap()]
+        );
+
+        // Offsets longer than a period reach back more than one period
+        let schedule: Schedule = "40 business days after month end".parse().unwrap();
+        let runs = schedule
+            .between(date(2024, 3, 1), date(2024, 3, 31))
+            .unwrap();
+        assert_eq!(
+            runs,
+            [(date(2024, 3, 27), DatePeriod::month(2024, 1).unwrap())]
+        );
+        assert_eq!(
+            schedule.closed_periods(date(2024, 3, 27)),
+            [DatePeriod::month(2024, 1).unwrap()]
+        );
+
+        let daily: Schedule = "1st business day after day end".parse().unwrap();
+        let runs = daily.between(date(2024, 3, 4), date(2024, 3, 4)).unwrap();
+        // Monday reports on Friday, Saturday and Sunday
+        assert_eq!(
+            runs.iter()
+                .map(|(_, day)| day.to_date().unwrap())
+                .collect::<Vec<_>>(),
+            [date(2024, 3, 1), date(2024, 3, 2), date(2024, 3, 3)]
+        );
+        assert_eq!(
+            daily.closed_periods(date(2024, 3, 4)),
+            runs.iter().map(|(_, day)| *day).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            daily.closed_periods(date(2024, 3, 5)),
+            [DatePeriod::daily_from_ymd(2024, 3, 4).unwrap()]
+        );
+        assert!(daily.closed_periods(date(2024, 3, 9)).is_empty());
+    }
+
+    #[test]
+    fn test_text_and_serde() {
+        for (text, canonical) in [
+            ("every closed month", "every closed month"),
+            ("Every Closed Q", "every closed quarter"),
+            ("1 day after year end", "every closed year"),
+            (
+                "on the 2nd business day after quarter end",
+                "2nd business day after quarter end",
+            ),
+            ("11 days after month end", "11th day after month end"),
+            (
+                "22 business days after month end",
+                "22nd business day after month end",
+            ),
+            ("3rd day after day end", "3rd day after day end"),
+        ] {
+            let schedule: Schedule = text.parse().unwrap();
+            assert_eq!(schedule.to_string(), canonical);
+            assert_eq!(canonical.parse::<Schedule>().unwrap(), schedule);
+            let json = serde_json::to_string(&schedule).unwrap();
+            assert_eq!(serde_json::from_str::<Schedule>(&json).unwrap(), schedule);
+        }
+
+        for invalid in [
+            "",
+            "every month",
+            "every closed week",
+            "0 days after month end",
+            "2nd business day before quarter end",
+            "second day after month end",
+            "2nd day after month",
+        ] {
+            assert!(invalid.parse::<Schedule>().is_err(), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,697 @@
+//! Data-driven term calendars, e.g. academic Fall / Spring / Summer terms.
+//!
+//! Terms don't follow a rule: each school publishes its own start and end
+//! dates every year. A [`TermCalendar`] is a table of named [`Term`]s per
+//! year, loaded from JSON (or TOML with the `toml` feature), with lookup,
+//! navigation and range generation over the table. Days between two terms
+//! belong to no term and are reported as [`Gap`]s.
+//!
+//! ```rust
+//! use range_date::term::{Segment, TermCalendar};
+//! use chrono::NaiveDate;
+//!
+//! let calendar = TermCalendar::from_json(r#"{
+//!     "years": {
+//!         "2024": [
+//!             {"name": "Fall", "start": "2024-09-02", "end": "2024-12-20"},
+//!             {"name": "Spring", "start": "2025-01-13", "end": "2025-05-09"}
+//!         ]
+//!     }
+//! }"#).unwrap();
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
+//! let fall = calendar.from_date(date).unwrap();
+//! assert_eq!(fall.to_string(), "2024:Fall");
+//! assert_eq!(calendar.succ(&fall).unwrap().name(), "Spring");
+//!
+//! let winter_break = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+//! assert!(calendar.from_date(winter_break).is_err());
+//! assert!(matches!(
+//!     calendar.locate(winter_break),
+//!     Some(Segment::Gap(gap)) if gap.after().name() == "Spring"
+//! ));
+//! ```
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+/// A named term of a [`TermCalendar`], e.g. `2024:Fall`
+///
+/// `year` is the calendar's label for the year the term belongs to, such as
+/// the academic year 2024/25, so a `2024:Spring` term may lie in 2025.
+/// Terms order by their first day.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Term {
+    first_day: NaiveDate,
+    last_day: NaiveDate,
+    year: i32,
+    name: String,
+}
+
+impl Term {
+    /// Create a term covering `first_day..=last_day`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `name` is empty or contains `:`, or if
+    /// `first_day` is after `last_day`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::term::Term;
+    /// use chrono::NaiveDate;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 9, 2).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 12, 20).unwrap();
+    /// let fall = Term::new(2024, "Fall", start, end).unwrap();
+    /// assert_eq!(fall.to_string(), "2024:Fall");
+    /// assert!(Term::new(2024, "Fall", end, start).is_err());
+    /// ```
+    pub fn new(
+        year: i32,
+        name: impl Into<String>,
+        first_day: NaiveDate,
+        last_day: NaiveDate,
+    ) -> anyhow::Result<Self> {
+        let name = name.into();
+        if name.trim().is_empty() || name.contains(':') {
+            return Err(anyhow::anyhow!(
+                "Term name must be non-empty and must not contain ':', got: {:?}",
+                name
+            ));
+        }
+        if first_day > last_day {
+            return Err(anyhow::anyhow!(
+                "Term {}:{} starts on {} after it ends on {}",
+                year,
+                name,
+                first_day,
+                last_day
+            ));
+        }
+        Ok(Term {
+            first_day,
+            last_day,
+            year,
+            name,
+        })
+    }
+
+    /// Get the year label of the term
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Get the name of the term
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the first day of the term
+    pub fn get_first_day(&self) -> NaiveDate {
+        self.first_day
+    }
+
+    /// Get the last day of the term (inclusive)
+    pub fn get_last_day(&self) -> NaiveDate {
+        self.last_day
+    }
+
+    /// Check if `date` lies within the term
+    pub fn contains_date(&self, date: NaiveDate) -> bool {
+        self.first_day <= date && date <= self.last_day
+    }
+}
+
+impl std::fmt::Display for Term {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.year, self.name)
+    }
+}
+
+/// The days between two consecutive terms of a [`TermCalendar`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Gap {
+    before: Term,
+    after: Term,
+}
+
+impl Gap {
+    /// Get the term ending just before the gap
+    pub fn before(&self) -> &Term {
+        &self.before
+    }
+
+    /// Get the term starting just after the gap
+    pub fn after(&self) -> &Term {
+        &self.after
+    }
+
+    /// Get the first day of the gap
+    pub fn get_first_day(&self) -> NaiveDate {
+        self.before
+            .last_day
+            .succ_opt()
+            .unwrap_or(self.before.last_day)
+    }
+
+    /// Get the last day of the gap (inclusive)
+    pub fn get_last_day(&self) -> NaiveDate {
+        self.after
+            .first_day
+            .pred_opt()
+            .unwrap_or(self.after.first_day)
+    }
+
+    /// Check if `date` lies within the gap
+    pub fn contains_date(&self, date: NaiveDate) -> bool {
+        self.before.last_day < date && date < self.after.first_day
+    }
+}
+
+impl std::fmt::Display for Gap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "gap {} to {} between {} and {}",
+            self.get_first_day(),
+            self.get_last_day(),
+            self.before,
+            self.after
+        )
+    }
+}
+
+/// A stretch of a [`TermCalendar`]: either a term or the gap between two
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Segment {
+    Term(Term),
+    Gap(Gap),
+}
+
+/// A table of named terms per year
+///
+/// Terms may not overlap but need not be contiguous. The JSON form maps each
+/// year label to its terms:
+///
+/// ```json
+/// {
+///     "years": {
+///         "2024": [
+///             {"name": "Fall", "start": "2024-09-02", "end": "2024-12-20"},
+///             {"name": "Spring", "start": "2025-01-13", "end": "2025-05-09"}
+///         ]
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TermCalendar {
+    terms: Vec<Term>,
+}
+
+impl TermCalendar {
+    /// Create a calendar from its terms, in any order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if two terms overlap or share the same year and name.
+    pub fn new(terms: impl IntoIterator<Item = Term>) -> anyhow::Result<Self> {
+        let mut terms: Vec<Term> = terms.into_iter().collect();
+        terms.sort();
+        for pair in terms.windows(2) {
+            if pair[0].last_day >= pair[1].first_day {
+                return Err(anyhow::anyhow!("Terms {} and {} overlap", pair[0], pair[1]));
+            }
+        }
+        for (i, term) in terms.iter().enumerate() {
+            if terms[..i]
+                .iter()
+                .any(|other| other.year == term.year && other.name == term.name)
+            {
+                return Err(anyhow::anyhow!("Duplicate term {}", term));
+            }
+        }
+        Ok(TermCalendar { terms })
+    }
+
+    /// Load a calendar from its JSON form, see [`TermCalendar`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed, a date is not in
+    /// `YYYY-MM-DD` form, or the terms are invalid (see [`TermCalendar::new`]).
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        let value: Value = serde_json::from_str(json)?;
+        Self::from_value(&value)
+    }
+
+    /// Load a calendar from TOML with the same structure as the JSON form
+    ///
+    /// Dates may be TOML date literals or `YYYY-MM-DD` strings:
+    ///
+    /// ```toml
+    /// [[years.2024]]
+    /// name = "Fall"
+    /// start = 2024-09-02
+    /// end = 2024-12-20
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the TOML is malformed or describes an invalid
+    /// calendar, see [`TermCalendar::from_json`].
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> anyhow::Result<Self> {
+        let value: toml::Value = toml::from_str(toml)?;
+        Self::from_value(&toml_to_json(value))
+    }
+
+    /// Get all terms, ordered by their first day
+    pub fn terms(&self) -> &[Term] {
+        &self.terms
+    }
+
+    /// Get the term with year label `year` and name `name`
+    pub fn get(&self, year: i32, name: &str) -> Option<&Term> {
+        self.terms
+            .iter()
+            .find(|term| term.year == year && term.name == name)
+    }
+
+    /// Look up a term by its string form, e.g. `"2024:Fall"`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not of the form `<year>:<name>` or the
+    /// calendar has no such term.
+    pub fn parse_term(&self, s: &str) -> anyhow::Result<Term> {
+        let (year, name) = s
+            .trim()
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("Invalid term format: {}", s))?;
+        let year: i32 = year
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid term format: {}", s))?;
+        self.get(year, name)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No term {} in calendar", s))
+    }
+
+    /// Get the term containing `date`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the surrounding terms if `date` falls in a gap
+    /// between terms, or if it lies outside the calendar.
+    pub fn from_date(&self, date: NaiveDate) -> anyhow::Result<Term> {
+        match self.locate(date) {
+            Some(Segment::Term(term)) => Ok(term),
+            Some(Segment::Gap(gap)) => Err(anyhow::anyhow!("Date {} is in the {}", date, gap)),
+            None => Err(anyhow::anyhow!(
+                "Date {} is outside the term calendar",
+                date
+            )),
+        }
+    }
+
+    /// Get the term or gap containing `date`
+    ///
+    /// Returns `None` if `date` lies before the first or after the last term.
+    pub fn locate(&self, date: NaiveDate) -> Option<Segment> {
+        let position = self.terms.partition_point(|term| term.first_day <= date);
+        let term = self.terms.get(position.checked_sub(1)?)?;
+        if term.contains_date(date) {
+            return Some(Segment::Term(term.clone()));
+        }
+        let after = self.terms.get(position)?;
+        Some(Segment::Gap(Gap {
+            before: term.clone(),
+            after: after.clone(),
+        }))
+    }
+
+    /// Get all gaps between consecutive terms, in order
+    pub fn gaps(&self) -> Vec<Gap> {
+        self.terms
+            .windows(2)
+            .filter(|pair| pair[0].last_day.succ_opt() != Some(pair[1].first_day))
+            .map(|pair| Gap {
+                before: pair[0].clone(),
+                after: pair[1].clone(),
+            })
+            .collect()
+    }
+
+    /// Get the term after `term`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `term` is not in the calendar or is its last term.
+    pub fn succ(&self, term: &Term) -> anyhow::Result<Term> {
+        self.offset_n(term, 1)
+    }
+
+    /// Get the term before `term`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `term` is not in the calendar or is its first term.
+    pub fn pred(&self, term: &Term) -> anyhow::Result<Term> {
+        self.offset_n(term, -1)
+    }
+
+    /// Get the term `n` terms after `term` (before it if `n` is negative)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `term` is not in the calendar or the result would
+    /// lie outside it.
+    pub fn offset_n(&self, term: &Term, n: i64) -> anyhow::Result<Term> {
+        let position = self
+            .terms
+            .binary_search(term)
+            .map_err(|_| anyhow::anyhow!("Term {} is not in the calendar", term))?;
+        i64::try_from(position)
+            .ok()
+            .and_then(|position| position.checked_add(n))
+            .and_then(|position| usize::try_from(position).ok())
+            .and_then(|position| self.terms.get(position))
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Term {} offset by {} is outside the calendar", term, n))
+    }
+
+    /// Get all terms overlapping the dates `start..=end`
+    /// Returns an empty vector if start > end
+    pub fn between(&self, start: NaiveDate, end: NaiveDate) -> Vec<Term> {
+        self.terms
+            .iter()
+            .filter(|term| term.first_day <= end && start <= term.last_day)
+            .cloned()
+            .collect()
+    }
+
+    /// Get all terms and gaps overlapping the dates `start..=end`, in order
+    /// Returns an empty vector if start > end
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::term::{Segment, Term, TermCalendar};
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+    /// let calendar = TermCalendar::new([
+    ///     Term::new(2024, "Spring", date(1, 8), date(5, 10)).unwrap(),
+    ///     Term::new(2024, "Summer", date(6, 3), date(8, 9)).unwrap(),
+    /// ]).unwrap();
+    /// let segments = calendar.segments_between(date(5, 1), date(6, 30));
+    /// assert_eq!(segments.len(), 3);
+    /// assert!(matches!(segments[1], Segment::Gap(_)));
+    /// ```
+    pub fn segments_between(&self, start: NaiveDate, end: NaiveDate) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        for (i, term) in self.terms.iter().enumerate() {
+            if let Some(previous) = i.checked_sub(1).and_then(|i| self.terms.get(i)) {
+                let gap = Gap {
+                    before: previous.clone(),
+                    after: term.clone(),
+                };
+                if previous.last_day.succ_opt() != Some(term.first_day)
+                    && gap.get_first_day() <= end
+                    && start <= gap.get_last_day()
+                {
+                    segments.push(Segment::Gap(gap));
+                }
+            }
+            if term.first_day <= end && start <= term.last_day {
+                segments.push(Segment::Term(term.clone()));
+            }
+        }
+        segments
+    }
+
+    /// Build a calendar from the JSON data model
+    fn from_value(value: &Value) -> anyhow::Result<Self> {
+        let years = value
+            .get("years")
+            .and_then(Value::as_object)
+            .ok_or_else(|| anyhow::anyhow!("Term calendar must have a \"years\" table"))?;
+        let mut terms = Vec::new();
+        for (year, entries) in years {
+            let year: i32 = year
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid year label: {}", year))?;
+            let entries = entries
+                .as_array()
+                .ok_or_else(|| anyhow::anyhow!("Terms of {} must be a list", year))?;
+            for entry in entries {
+                let field = |key: &str| {
+                    entry.get(key).and_then(Value::as_str).ok_or_else(|| {
+                        anyhow::anyhow!("Term of {} is missing string field \"{}\"", year, key)
+                    })
+                };
+                let date = |key: &str| {
+                    let value = field(key)?;
+                    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                        .map_err(|_| anyhow::anyhow!("Invalid date for \"{}\": {}", key, value))
+                };
+                terms.push(Term::new(
+                    year,
+                    field("name")?,
+                    date("start")?,
+                    date("end")?,
+                )?);
+            }
+        }
+        Self::new(terms)
+    }
+
+    /// The JSON data model of the calendar
+    fn to_value(&self) -> Value {
+        let mut years = Map::new();
+        for term in &self.terms {
+            let entry = serde_json::json!({
+                "name": term.name,
+                "start": term.first_day.format("%Y-%m-%d").to_string(),
+                "end": term.last_day.format("%Y-%m-%d").to_string(),
+            });
+            if let Value::Array(entries) = years
+                .entry(term.year.to_string())
+                .or_insert_with(|| Value::Array(Vec::new()))
+            {
+                entries.push(entry);
+            }
+        }
+        serde_json::json!({ "years": years })
+    }
+}
+
+/// Convert TOML to the JSON data model, turning date literals into strings
+#[cfg(feature = "toml")]
+fn toml_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::from(i),
+        toml::Value::Float(f) => Value::from(f),
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(dt) => Value::String(dt.to_string()),
+        toml::Value::Array(items) => Value::Array(items.into_iter().map(toml_to_json).collect()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, toml_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+impl Serialize for TermCalendar {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.to_value().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TermCalendar {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<TermCalendar, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        TermCalendar::from_value(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    const JSON: &str = r#"{
+        "years": {
+            "2024": [
+                {"name": "Spring", "start": "2025-01-13", "end": "2025-05-09"},
+                {"name": "Fall", "start": "2024-09-02", "end": "2024-12-20"},
+                {"name": "Summer", "start": "2025-05-10", "end": "2025-08-15"}
+            ],
+            "2025": [
+                {"name": "Fall", "start": "2025-09-01", "end": "2025-12-19"}
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn test_lookup_and_gaps() {
+        let calendar = TermCalendar::from_json(JSON).unwrap();
+        let names: Vec<String> = calendar.terms().iter().map(Term::to_string).collect();
+        assert_eq!(
+            names,
+            ["2024:Fall", "2024:Spring", "2024:Summer", "2025:Fall"]
+        );
+
+        let fall = calendar.from_date(date(2024, 9, 2)).unwrap();
+        assert_eq!(fall, calendar.parse_term("2024:Fall").unwrap());
+        assert!(fall.contains_date(date(2024, 12, 20)));
+        assert_eq!(
+            calendar.from_date(date(2025, 5, 10)).unwrap().name(),
+            "Summer"
+        );
+
+        let error = calendar
+            .from_date(date(2025, 1, 1))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("2024:Fall") && error.contains("2024:Spring"),
+            "{}",
+            error
+        );
+        assert!(calendar.from_date(date(2024, 8, 1)).is_err());
+        assert_eq!(calendar.locate(date(2024, 8, 1)), None);
+        assert_eq!(calendar.locate(date(2026, 1, 1)), None);
+
+        // Spring and Summer are contiguous, so there are two gaps
+        let gaps = calendar.gaps();
+        assert_eq!(gaps.len(), 2);
+        assert_eq!(gaps[0].get_first_day(), date(2024, 12, 21));
+        assert_eq!(gaps[0].get_last_day(), date(2025, 1, 12));
+        assert_eq!(gaps[1].before().name(), "Summer");
+        assert_eq!(
+            calendar.locate(date(2025, 8, 20)),
+            Some(Segment::Gap(gaps[1].clone()))
+        );
+        assert!(calendar.parse_term("2023:Fall").is_err());
+        assert!(calendar.parse_term("Fall").is_err());
+    }
+
+    #[test]
+    fn test_navigation_and_ranges() {
+        let calendar = TermCalendar::from_json(JSON).unwrap();
+        let fall = calendar.get(2024, "Fall").unwrap();
+        let spring = calendar.succ(fall).unwrap();
+        assert_eq!(spring.name(), "Spring");
+        assert_eq!(&calendar.pred(&spring).unwrap(), fall);
+        assert_eq!(calendar.offset_n(fall, 3).unwrap().to_string(), "2025:Fall");
+        assert!(calendar.pred(fall).is_err());
+        assert!(calendar.offset_n(fall, 4).is_err());
+        assert!(calendar.offset_n(fall, i64::MIN).is_err());
+        let stranger = Term::new(2024, "Winter", date(2024, 12, 22), date(2025, 1, 5)).unwrap();
+        assert!(calendar.succ(&stranger).is_err());
+
+        let terms = calendar.between(date(2024, 12, 1), date(2025, 6, 1));
+        assert_eq!(terms.len(), 3);
+        assert!(
+            calendar
+                .between(date(2025, 6, 1), date(2024, 12, 1))
+                .is_empty()
+        );
+
+        let segments = calendar.segments_between(date(2024, 12, 25), date(2025, 9, 1));
+        let kinds: Vec<String> = segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Term(term) => term.to_string(),
+                Segment::Gap(gap) => format!("gap before {}", gap.after()),
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                "gap before 2024:Spring",
+                "2024:Spring",
+                "2024:Summer",
+                "gap before 2025:Fall",
+                "2025:Fall"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validation_and_serde() {
+        let calendar = TermCalendar::from_json(JSON).unwrap();
+        let json = serde_json::to_string(&calendar).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TermCalendar>(&json).unwrap(),
+            calendar
+        );
+
+        let overlapping = Term::new(2024, "Winter", date(2024, 12, 1), date(2025, 1, 20)).unwrap();
+        let mut terms = calendar.terms().to_vec();
+        terms.push(overlapping);
+        assert!(TermCalendar::new(terms).is_err());
+        let duplicate = Term::new(2024, "Fall", date(2026, 1, 1), date(2026, 2, 1)).unwrap();
+        let mut terms = calendar.terms().to_vec();
+        terms.push(duplicate);
+        assert!(TermCalendar::new(terms).is_err());
+
+        for invalid in [
+            r#"{}"#,
+            r#"{"years": {"x": []}}"#,
+            r#"{"years": {"2024": {}}}"#,
+            r#"{"years": {"2024": [{"name": "Fall", "start": "2024-09-02"}]}}"#,
+            r#"{"years": {"2024": [{"name": "Fall", "start": "2024-13-02", "end": "2024-12-20"}]}}"#,
+            r#"{"years": {"2024": [{"name": "", "start": "2024-09-02", "end": "2024-12-20"}]}}"#,
+            r#"{"years": {"2024": [{"name": "Fall", "start": "2024-12-20", "end": "2024-09-02"}]}}"#,
+        ] {
+            assert!(TermCalendar::from_json(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml() {
+        let calendar = TermCalendar::from_toml(
+            r#"
+            [[years.2024]]
+            name = "Fall"
+            start = 2024-09-02
+            end = 2024-12-20
+
+            [[years.2024]]
+            name = "Spring"
+            start = "2025-01-13"
+            end = "2025-05-09"
+
+            [[years.2024]]
+            name = "Summer"
+            start = 2025-05-10
+            end = 2025-08-15
+
+            [[years.2025]]
+            name = "Fall"
+            start = 2025-09-01
+            end = 2025-12-19
+            "#,
+        )
+        .unwrap();
+        assert_eq!(calendar, TermCalendar::from_json(JSON).unwrap());
+        assert!(TermCalendar::from_toml("[[years.2024]]\nname = \"Fall\"").is_err());
+    }
+}
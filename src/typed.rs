@@ -0,0 +1,712 @@
+//! Per-granularity period types.
+//!
+//! [`Year`], [`Quarter`], [`Month`] and [`Day`] wrap a [`DatePeriod`] of the
+//! matching [`PeriodKind`], so an API that only accepts months can say so in
+//! its signature instead of checking the variant at runtime. Each type has the
+//! navigation and boundary API of [`DatePeriod`], returning its own type, and
+//! implements [`Period`].
+//!
+//! ```rust
+//! use range_date::range_type::DatePeriod;
+//! use range_date::typed::{Month, Quarter};
+//!
+//! fn close_month(month: Month) -> String {
+//!     format!("closing {}", month)
+//! }
+//!
+//! let month = Month::new(2024, 12).unwrap();
+//! assert_eq!(close_month(month.succ().unwrap()), "closing 2025M1");
+//! assert_eq!(month.aggregate(), Quarter::new(2024, 4).unwrap());
+//!
+//! // Conversions to and from the loose type
+//! let period: DatePeriod = month.into();
+//! assert_eq!(Month::try_from(period).unwrap(), month);
+//! assert!(Month::try_from(DatePeriod::year(2024).unwrap()).is_err());
+//! ```
+
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::period::Period;
+use crate::range_type::{DatePeriod, PeriodKind};
+use crate::year_block::YearBlock;
+
+/// Define a newtype over [`DatePeriod`] restricted to one [`PeriodKind`],
+/// with the kind-independent part of its API.
+macro_rules! typed_period {
+    ($(#[$meta:meta])* $name:ident, $kind:expr, $example:literal, $parent:ident, $child:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(DatePeriod);
+
+        impl $name {
+            /// The granularity of this type
+            pub const KIND: PeriodKind = $kind;
+
+            /// Create the period containing `date`
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if `date` lies before [`DatePeriod::MIN_YEAR`].
+            pub fn from_date(date: NaiveDate) -> anyhow::Result<Self> {
+                DatePeriod::from_date(date, Self::KIND).map(Self)
+            }
+
+            /// Create the period containing `dt` in the time zone `tz`
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the local date lies before
+            /// [`DatePeriod::MIN_YEAR`].
+            pub fn from_datetime<T: TimeZone, Tz: TimeZone>(
+                dt: &DateTime<T>,
+                tz: &Tz,
+            ) -> anyhow::Result<Self> {
+                Self::from_date(dt.with_timezone(tz).date_naive())
+            }
+
+            /// Create the period at position `ordinal`, see
+            /// [`DatePeriod::from_ordinal`]
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if `ordinal` lies outside the supported years.
+            pub fn from_ordinal(ordinal: i64) -> anyhow::Result<Self> {
+                DatePeriod::from_ordinal(Self::KIND, ordinal).map(Self)
+            }
+
+            /// Generate all periods between two dates (inclusive)
+            /// Returns an empty vector if start > end
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if `start` lies before [`DatePeriod::MIN_YEAR`].
+            pub fn between(start: NaiveDate, end: NaiveDate) -> anyhow::Result<Vec<Self>> {
+                Ok(DatePeriod::between(start, end, Self::KIND)?
+                    .into_iter()
+                    .map(Self)
+                    .collect())
+            }
+
+            /// Generate all periods from `start` to `end` (inclusive)
+            /// Returns an empty vector if start > end
+            pub fn between_periods(start: Self, end: Self) -> Vec<Self> {
+                DatePeriod::between_periods(start.0, end.0)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(Self)
+                    .collect()
+            }
+
+            /// Get the first day of the period
+            ///
+            /// # Errors
+            ///
+            /// See [`DatePeriod::get_first_day`].
+            pub fn get_first_day(&self) -> anyhow::Result<NaiveDate> {
+                self.0.get_first_day()
+            }
+
+            /// Get the last day of the period
+            ///
+            /// # Errors
+            ///
+            /// See [`DatePeriod::get_last_day`].
+            pub fn get_last_day(&self) -> anyhow::Result<NaiveDate> {
+                self.0.get_last_day()
+            }
+
+            /// Check if `date` lies within the period
+            pub fn contains_date(&self, date: NaiveDate) -> bool {
+                self.0.contains_date(date)
+            }
+
+            /// Get the first instant of the period in `tz`, see
+            /// [`DatePeriod::start_instant`]
+            ///
+            /// # Errors
+            ///
+            /// See [`DatePeriod::start_instant`].
+            pub fn start_instant<Tz: TimeZone>(&self, tz: &Tz) -> anyhow::Result<DateTime<Tz>> {
+                self.0.start_instant(tz)
+            }
+
+            /// Get the first instant after the period in `tz`, see
+            /// [`DatePeriod::end_instant`]
+            ///
+            /// # Errors
+            ///
+            /// See [`DatePeriod::end_instant`].
+            pub fn end_instant<Tz: TimeZone>(&self, tz: &Tz) -> anyhow::Result<DateTime<Tz>> {
+                self.0.end_instant(tz)
+            }
+
+            /// Get the year of the period
+            pub fn get_year(&self) -> u32 {
+                self.0.get_year()
+            }
+
+            /// Get the position of the period, see [`DatePeriod::ordinal`]
+            pub fn ordinal(&self) -> i64 {
+                self.0.ordinal()
+            }
+
+            /// Get the number of periods from this period to `other`
+            pub fn distance_to(&self, other: &Self) -> i64 {
+                other.ordinal() - self.ordinal()
+            }
+
+            /// Get the successor (next) period
+            ///
+            /// # Errors
+            ///
+            /// Returns an error past [`DatePeriod::MAX_YEAR`].
+            pub fn succ(&self) -> anyhow::Result<Self> {
+                self.0.succ().map(Self)
+            }
+
+            /// Get the predecessor (previous) period
+            ///
+            /// # Errors
+            ///
+            /// Returns an error before [`DatePeriod::MIN_YEAR`].
+            pub fn pred(&self) -> anyhow::Result<Self> {
+                self.0.pred().map(Self)
+            }
+
+            /// Get the successor n periods ahead
+            ///
+            /// # Errors
+            ///
+            /// Returns an error past [`DatePeriod::MAX_YEAR`].
+            pub fn succ_n(&self, n: u32) -> anyhow::Result<Self> {
+                self.0.succ_n(n).map(Self)
+            }
+
+            /// Get the predecessor n periods back
+            ///
+            /// # Errors
+            ///
+            /// Returns an error before [`DatePeriod::MIN_YEAR`].
+            pub fn pred_n(&self, n: u32) -> anyhow::Result<Self> {
+                self.0.pred_n(n).map(Self)
+            }
+
+            /// Offset the period by n steps, forwards for positive n
+            ///
+            /// # Errors
+            ///
+            /// Returns an error outside the supported years.
+            pub fn offset_n(&self, n: i32) -> anyhow::Result<Self> {
+                self.0.offset_n(n).map(Self)
+            }
+
+            /// Get the successor n periods ahead, or `None` past
+            /// [`DatePeriod::MAX_YEAR`]
+            pub fn checked_succ_n(&self, n: u32) -> Option<Self> {
+                self.0.checked_succ_n(n).map(Self)
+            }
+
+            /// Get the predecessor n periods back, or `None` before
+            /// [`DatePeriod::MIN_YEAR`]
+            pub fn checked_pred_n(&self, n: u32) -> Option<Self> {
+                self.0.checked_pred_n(n).map(Self)
+            }
+
+            /// Offset the period by n steps, or `None` outside the supported
+            /// years
+            pub fn checked_offset_n(&self, n: i32) -> Option<Self> {
+                self.0.checked_offset_n(n).map(Self)
+            }
+
+            /// Offset the period by n steps, or `None` outside the supported
+            /// years
+            pub fn checked_add(&self, n: i64) -> Option<Self> {
+                self.0.checked_add(n).map(Self)
+            }
+
+            /// Offset the period by -n steps, or `None` outside the supported
+            /// years
+            pub fn checked_sub(&self, n: i64) -> Option<Self> {
+                self.0.checked_sub(n).map(Self)
+            }
+
+            /// Get the successor n periods ahead, stopping at
+            /// [`DatePeriod::MAX_YEAR`]
+            pub fn saturating_succ_n(&self, n: u32) -> Self {
+                Self(self.0.saturating_succ_n(n))
+            }
+
+            /// Get the predecessor n periods back, stopping at
+            /// [`DatePeriod::MIN_YEAR`]
+            pub fn saturating_pred_n(&self, n: u32) -> Self {
+                Self(self.0.saturating_pred_n(n))
+            }
+
+            /// Get the underlying [`DatePeriod`]
+            pub fn period(&self) -> DatePeriod {
+                self.0
+            }
+        }
+
+        impl From<$name> for DatePeriod {
+            fn from(period: $name) -> DatePeriod {
+                period.0
+            }
+        }
+
+        impl TryFrom<DatePeriod> for $name {
+            type Error = anyhow::Error;
+
+            fn try_from(period: DatePeriod) -> Result<Self, Self::Error> {
+                if period.kind() != Self::KIND {
+                    return Err(anyhow::anyhow!(
+                        "Expected a {} period, got: {}",
+                        Self::KIND,
+                        period
+                    ));
+                }
+                Ok(Self(period))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        #[doc = concat!("Parses the [`DatePeriod`] string form, e.g. `\"", $example, "\"`,")]
+        /// rejecting periods of any other kind
+        impl std::str::FromStr for $name {
+            type Err = anyhow::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::try_from(DatePeriod::parse(s)?)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::ser::Serializer,
+            {
+                self.0.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let period = DatePeriod::deserialize(deserializer)?;
+                Self::try_from(period).map_err(serde::de::Error::custom)
+            }
+        }
+
+        impl Period for $name {
+            type Parent = $parent;
+            type Child = $child;
+
+            fn get_first_day(&self) -> anyhow::Result<NaiveDate> {
+                self.0.get_first_day()
+            }
+
+            fn get_last_day(&self) -> anyhow::Result<NaiveDate> {
+                self.0.get_last_day()
+            }
+
+            fn checked_add(&self, n: i64) -> Option<Self> {
+                self.0.checked_add(n).map(Self)
+            }
+
+            fn containing(&self, date: NaiveDate) -> anyhow::Result<Self> {
+                Self::from_date(date)
+            }
+
+            fn parent(&self) -> Option<$parent> {
+                Self::KIND.parent().map(|_| $parent(self.0.aggregate()))
+            }
+
+            fn children(&self) -> Vec<$child> {
+                self.0.decompose().into_iter().map($child).collect()
+            }
+
+            fn contains_date(&self, date: NaiveDate) -> bool {
+                self.0.contains_date(date)
+            }
+        }
+
+        /// Offset by `n` periods, panicking outside the supported years
+        impl Add<i64> for $name {
+            type Output = $name;
+
+            fn add(self, n: i64) -> $name {
+                Self(self.0 + n)
+            }
+        }
+
+        /// Offset by `-n` periods, panicking outside the supported years
+        impl Sub<i64> for $name {
+            type Output = $name;
+
+            fn sub(self, n: i64) -> $name {
+                Self(self.0 - n)
+            }
+        }
+
+        impl AddAssign<i64> for $name {
+            fn add_assign(&mut self, n: i64) {
+                *self = *self + n;
+            }
+        }
+
+        impl SubAssign<i64> for $name {
+            fn sub_assign(&mut self, n: i64) {
+                *self = *self - n;
+            }
+        }
+
+        /// Number of periods from `other` to `self`
+        impl Sub<$name> for $name {
+            type Output = i64;
+
+            fn sub(self, other: $name) -> i64 {
+                other.distance_to(&self)
+            }
+        }
+    };
+}
+
+typed_period!(
+    /// A calendar year, e.g. `2024Y`
+    Year,
+    PeriodKind::Year,
+    "2024Y",
+    Year,
+    Quarter
+);
+
+typed_period!(
+    /// A calendar quarter, e.g. `2024Q1`
+    Quarter,
+    PeriodKind::Quarter,
+    "2024Q1",
+    Year,
+    Month
+);
+
+typed_period!(
+    /// A calendar month, e.g. `2024M3`
+    Month,
+    PeriodKind::Month,
+    "2024M3",
+    Quarter,
+    Day
+);
+
+typed_period!(
+    /// A single day, addressed by its day of the year, e.g. `2024D60`
+    Day,
+    PeriodKind::Daily,
+    "2024D60",
+    Month,
+    Day
+);
+
+impl Year {
+    /// Create a year with validation, see [`DatePeriod::year`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `year` is greater than [`DatePeriod::MAX_YEAR`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::typed::Year;
+    ///
+    /// let year = Year::new(2024).unwrap();
+    /// assert_eq!(year.to_string(), "2024Y");
+    /// assert_eq!(year.decompose().len(), 4);
+    /// ```
+    pub fn new(year: u32) -> anyhow::Result<Self> {
+        DatePeriod::year(year).map(Self)
+    }
+
+    /// Decompose the year into its quarters
+    pub fn decompose(&self) -> Vec<Quarter> {
+        self.0.decompose().into_iter().map(Quarter).collect()
+    }
+
+    /// Get the decade containing the year, e.g. `202X` for 2024
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the decade extends past [`DatePeriod::MAX_YEAR`].
+    pub fn decade(&self) -> anyhow::Result<YearBlock> {
+        YearBlock::decade(self.get_year())
+    }
+
+    /// Get the century containing the year, e.g. `20XX` for 2024
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the century extends past [`DatePeriod::MAX_YEAR`].
+    pub fn century(&self) -> anyhow::Result<YearBlock> {
+        YearBlock::century(self.get_year())
+    }
+
+    /// Get the block of `length_years` years, aligned to `anchor_year`, that
+    /// contains the year, see [`YearBlock::from_year`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `length_years` is zero or the block does not lie
+    /// within the supported years.
+    pub fn block(&self, length_years: u32, anchor_year: u32) -> anyhow::Result<YearBlock> {
+        YearBlock::from_year(self.get_year(), length_years, anchor_year)
+    }
+}
+
+impl Quarter {
+    /// Create a quarter with validation, see [`DatePeriod::quarter`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `quarter` is not in `1..=4` or `year` is greater
+    /// than [`DatePeriod::MAX_YEAR`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::typed::{Quarter, Year};
+    ///
+    /// let quarter = Quarter::new(2024, 3).unwrap();
+    /// assert_eq!(quarter.quarter(), 3);
+    /// assert_eq!(quarter.aggregate(), Year::new(2024).unwrap());
+    /// assert!(Quarter::new(2024, 5).is_err());
+    /// ```
+    pub fn new(year: u32, quarter: u32) -> anyhow::Result<Self> {
+        DatePeriod::quarter(year, quarter).map(Self)
+    }
+
+    /// Get the quarter number (1-4)
+    pub fn quarter(&self) -> u32 {
+        self.0.value()
+    }
+
+    /// Get the year containing the quarter
+    pub fn aggregate(&self) -> Year {
+        Year(self.0.aggregate())
+    }
+
+    /// Decompose the quarter into its months
+    pub fn decompose(&self) -> Vec<Month> {
+        self.0.decompose().into_iter().map(Month).collect()
+    }
+}
+
+impl Month {
+    /// Create a month with validation, see [`DatePeriod::month`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `month` is not in `1..=12` or `year` is greater
+    /// than [`DatePeriod::MAX_YEAR`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::typed::{Month, Quarter};
+    ///
+    /// let month = Month::new(2024, 2).unwrap();
+    /// assert_eq!(month.month(), 2);
+    /// assert_eq!(month.aggregate(), Quarter::new(2024, 1).unwrap());
+    /// assert_eq!(month.decompose().len(), 29);
+    /// ```
+    pub fn new(year: u32, month: u32) -> anyhow::Result<Self> {
+        DatePeriod::month(year, month).map(Self)
+    }
+
+    /// Get the month number (1-12)
+    pub fn month(&self) -> u32 {
+        self.0.value()
+    }
+
+    /// Get the quarter containing the month
+    pub fn aggregate(&self) -> Quarter {
+        Quarter(self.0.aggregate())
+    }
+
+    /// Decompose the month into its days
+    pub fn decompose(&self) -> Vec<Day> {
+        self.0.decompose().into_iter().map(Day).collect()
+    }
+}
+
+impl Day {
+    /// Create a day from its year and day of the year, see
+    /// [`DatePeriod::daily`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `day` is not a valid day of `year` or `year` is
+    /// greater than [`DatePeriod::MAX_YEAR`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::typed::{Day, Month};
+    /// use chrono::NaiveDate;
+    ///
+    /// let day = Day::new(2024, 60).unwrap();
+    /// assert_eq!(day.day_of_year(), 60);
+    /// assert_eq!(day.date(), NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+    /// assert_eq!(day.aggregate(), Month::new(2024, 2).unwrap());
+    /// ```
+    pub fn new(year: u32, day: u32) -> anyhow::Result<Self> {
+        DatePeriod::daily(year, day).map(Self)
+    }
+
+    /// Create a day from its calendar date, see
+    /// [`DatePeriod::daily_from_ymd`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `month`/`day` is not a valid date in `year` or
+    /// `year` is greater than [`DatePeriod::MAX_YEAR`].
+    pub fn from_ymd(year: u32, month: u32, day: u32) -> anyhow::Result<Self> {
+        DatePeriod::daily_from_ymd(year, month, day).map(Self)
+    }
+
+    /// Get the day of the year (1-366)
+    pub fn day_of_year(&self) -> u32 {
+        self.0.value()
+    }
+
+    /// Get the month (1-12)
+    pub fn month(&self) -> u32 {
+        self.date().month()
+    }
+
+    /// Get the day of the month (1-31)
+    pub fn day_of_month(&self) -> u32 {
+        self.date().day()
+    }
+
+    /// Get the calendar date of the day
+    pub fn date(&self) -> NaiveDate {
+        // Every valid day lies within NaiveDate's range
+        self.0.to_date().unwrap_or(NaiveDate::MIN)
+    }
+
+    /// Get the month containing the day
+    pub fn aggregate(&self) -> Month {
+        Month(self.0.aggregate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generic_span<P: Period>(start: P, n: u32) -> anyhow::Result<(NaiveDate, NaiveDate)> {
+        Ok((start.get_first_day()?, start.succ_n(n)?.get_last_day()?))
+    }
+
+    #[test]
+    fn test_typed_matches_date_period() {
+        let date = NaiveDate::from_ymd_opt(2024, 8, 15).unwrap();
+        let typed: [DatePeriod; 4] = [
+            Year::from_date(date).unwrap().into(),
+            Quarter::from_date(date).unwrap().into(),
+            Month::from_date(date).unwrap().into(),
+            Day::from_date(date).unwrap().into(),
+        ];
+        for (kind, period) in PeriodKind::ALL.into_iter().zip(typed) {
+            let expected = DatePeriod::from_date(date, kind).unwrap();
+            assert_eq!(period, expected);
+            assert_eq!(period.succ_n(5).unwrap(), expected.succ_n(5).unwrap());
+        }
+
+        let month = Month::from_date(date).unwrap();
+        assert_eq!(
+            month.succ_n(5).unwrap().period(),
+            month.period().succ_n(5).unwrap()
+        );
+        assert_eq!(month + 5 - month, 5);
+        assert_eq!(month.checked_add(i64::MAX), None);
+        assert_eq!(Day::from_date(date).unwrap().date(), date);
+    }
+
+    #[test]
+    fn test_conversions_and_parsing() {
+        let quarter = Quarter::new(2024, 2).unwrap();
+        let period: DatePeriod = quarter.into();
+        assert_eq!(Quarter::try_from(period).unwrap(), quarter);
+        assert!(Month::try_from(period).is_err());
+        assert!(Year::try_from(period).is_err());
+
+        assert_eq!("2024Q2".parse::<Quarter>().unwrap(), quarter);
+        assert!("2024M2".parse::<Quarter>().is_err());
+        assert!("2024Q5".parse::<Quarter>().is_err());
+
+        let json = serde_json::to_string(&quarter).unwrap();
+        assert_eq!(json, "\"2024Q2\"");
+        assert_eq!(serde_json::from_str::<Quarter>(&json).unwrap(), quarter);
+        assert!(serde_json::from_str::<Month>(&json).is_err());
+    }
+
+    #[test]
+    fn test_hierarchy_and_ranges() {
+        let year = Year::new(2024).unwrap();
+        let days: Vec<Day> = year
+            .decompose()
+            .iter()
+            .flat_map(Quarter::decompose)
+            .flat_map(|month| month.decompose())
+            .collect();
+        assert_eq!(days.len(), 366);
+        assert!(
+            days.iter()
+                .all(|day| day.aggregate().aggregate().aggregate() == year)
+        );
+
+        let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+        let months = Month::between(start, end).unwrap();
+        assert_eq!(months.len(), 4);
+        assert_eq!(Month::between_periods(months[0], months[3]), months);
+        assert!(Month::between_periods(months[3], months[0]).is_empty());
+        assert_eq!(months[0].distance_to(&months[3]), 3);
+    }
+
+    #[test]
+    fn test_generic_over_period() {
+        let expected = (
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 6, 30).unwrap(),
+        );
+        assert_eq!(
+            generic_span(Quarter::new(2024, 1).unwrap(), 1).unwrap(),
+            expected
+        );
+        assert_eq!(
+            generic_span(Month::new(2024, 1).unwrap(), 5).unwrap(),
+            expected
+        );
+        assert_eq!(
+            generic_span(DatePeriod::month(2024, 1).unwrap(), 5).unwrap(),
+            expected
+        );
+        assert_eq!(
+            generic_span(Day::new(2024, 1).unwrap(), 181).unwrap(),
+            expected
+        );
+        assert!(generic_span(Year::new(DatePeriod::MAX_YEAR).unwrap(), 1).is_err());
+    }
+}
@@ -0,0 +1,446 @@
+//! Multi-year periods: decades, centuries and N-year blocks.
+//!
+//! Long-horizon planning and demographic data are reported per decade or per
+//! 5-year block. A [`YearBlock`] is `length_years` consecutive calendar years.
+//! Blocks of one length are aligned to an anchor year, so with an anchor of
+//! 2000 the 5-year blocks are 2000-2004, 2005-2009, and so on. Decades and
+//! centuries are anchored at year 0, so the decade `202X` covers 2020-2029
+//! and the century `20XX` covers 2000-2099.
+//!
+//! ```rust
+//! use range_date::period::Period;
+//! use range_date::typed::Year;
+//! use range_date::year_block::YearBlock;
+//!
+//! let year = Year::new(2024).unwrap();
+//! let decade = year.decade().unwrap();
+//! assert_eq!(decade.to_string(), "202X");
+//! assert_eq!(decade.decompose().len(), 10);
+//! assert_eq!(decade.succ().unwrap().to_string(), "203X");
+//!
+//! let block = year.block(5, 2001).unwrap();
+//! assert_eq!(block.to_string(), "2021/5Y");
+//! assert_eq!(block.last_year(), 2025);
+//! ```
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::period::Period;
+use crate::range_type::DatePeriod;
+use crate::typed::Year;
+
+/// `length_years` consecutive calendar years starting at `first_year`
+///
+/// The textual form is `<first_year>/<length>Y`, e.g. `2020/5Y` for
+/// 2020-2024. Decades and centuries aligned to year 0 use the shorter `202X`
+/// and `20XX` forms; both forms are accepted when parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearBlock {
+    first_year: u32,
+    length_years: u32,
+}
+
+impl YearBlock {
+    /// Create the block of `length_years` years starting at `first_year`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `length_years` is zero or the block extends past
+    /// [`DatePeriod::MAX_YEAR`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::year_block::YearBlock;
+    ///
+    /// let block = YearBlock::new(2020, 5).unwrap();
+    /// assert_eq!(block.last_year(), 2024);
+    /// assert!(YearBlock::new(2020, 0).is_err());
+    /// ```
+    pub fn new(first_year: u32, length_years: u32) -> anyhow::Result<Self> {
+        if length_years == 0 {
+            return Err(anyhow::anyhow!("Year block length must be at least 1 year"));
+        }
+        let block = YearBlock {
+            first_year,
+            length_years,
+        };
+        if block.checked_last_year().is_none() {
+            return Err(anyhow::anyhow!(
+                "Block of {} years from {} extends past year {}",
+                length_years,
+                first_year,
+                DatePeriod::MAX_YEAR
+            ));
+        }
+        Ok(block)
+    }
+
+    /// Create the block of `length_years` years, aligned to `anchor_year`,
+    /// that contains `year`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `length_years` is zero or the containing block
+    /// does not lie within [`DatePeriod::MIN_YEAR`]`..=`[`DatePeriod::MAX_YEAR`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::year_block::YearBlock;
+    ///
+    /// let block = YearBlock::from_year(2024, 5, 2001).unwrap();
+    /// assert_eq!(block.first_year(), 2021);
+    /// assert!(YearBlock::from_year(0, 5, 2001).is_err()); // would start at -4
+    /// ```
+    pub fn from_year(year: u32, length_years: u32, anchor_year: u32) -> anyhow::Result<Self> {
+        if length_years == 0 {
+            return Err(anyhow::anyhow!("Year block length must be at least 1 year"));
+        }
+        let length = length_years as i64;
+        let offset = (year as i64 - anchor_year as i64).div_euclid(length);
+        let first_year = anchor_year as i64 + offset * length;
+        let first_year = u32::try_from(first_year).map_err(|_| {
+            anyhow::anyhow!(
+                "Block of {} years containing {} starts before year {}",
+                length_years,
+                year,
+                DatePeriod::MIN_YEAR
+            )
+        })?;
+        Self::new(first_year, length_years)
+    }
+
+    /// Create the block of `length_years` years, aligned to `anchor_year`,
+    /// that contains `date`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `length_years` is zero or the containing block
+    /// does not lie within the supported years.
+    pub fn from_date(date: NaiveDate, length_years: u32, anchor_year: u32) -> anyhow::Result<Self> {
+        let year = u32::try_from(date.year()).map_err(|_| {
+            anyhow::anyhow!("Date {} is before year {}", date, DatePeriod::MIN_YEAR)
+        })?;
+        Self::from_year(year, length_years, anchor_year)
+    }
+
+    /// Create the decade containing `year`, e.g. `202X` for 2024
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the decade extends past [`DatePeriod::MAX_YEAR`].
+    pub fn decade(year: u32) -> anyhow::Result<Self> {
+        Self::from_year(year, 10, 0)
+    }
+
+    /// Create the century containing `year`, e.g. `20XX` for 2024
+    ///
+    /// Centuries are aligned to year 0, so `20XX` covers 2000-2099.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the century extends past [`DatePeriod::MAX_YEAR`].
+    pub fn century(year: u32) -> anyhow::Result<Self> {
+        Self::from_year(year, 100, 0)
+    }
+
+    /// Generate all blocks between two dates (inclusive)
+    /// Returns an empty vector if start > end
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `length_years` is zero or a block lies outside
+    /// the supported years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_date::year_block::YearBlock;
+    /// use chrono::NaiveDate;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(1995, 6, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let decades = YearBlock::between(start, end, 10, 0).unwrap();
+    /// assert_eq!(decades.len(), 4);
+    /// assert_eq!(decades[0].to_string(), "199X");
+    /// ```
+    pub fn between(
+        start: NaiveDate,
+        end: NaiveDate,
+        length_years: u32,
+        anchor_year: u32,
+    ) -> anyhow::Result<Vec<YearBlock>> {
+        if start > end {
+            return Ok(vec![]);
+        }
+        let first = Self::from_date(start, length_years, anchor_year)?;
+        let last = Self::from_date(end, length_years, anchor_year)?;
+        Ok((first.first_year..=last.first_year)
+            .step_by(length_years as usize)
+            .map(|first_year| YearBlock {
+                first_year,
+                ..first
+            })
+            .collect())
+    }
+
+    /// Get the first year of the block
+    pub fn first_year(&self) -> u32 {
+        self.first_year
+    }
+
+    /// Get the last year of the block (inclusive)
+    pub fn last_year(&self) -> u32 {
+        self.checked_last_year().unwrap_or(DatePeriod::MAX_YEAR)
+    }
+
+    /// Get the number of years in the block
+    pub fn length_years(&self) -> u32 {
+        self.length_years
+    }
+
+    /// Get the first day of the block
+    pub fn get_first_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.first_year as i32, 1, 1).unwrap_or(NaiveDate::MAX)
+    }
+
+    /// Get the last day of the block (inclusive)
+    pub fn get_last_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.last_year() as i32, 12, 31).unwrap_or(NaiveDate::MAX)
+    }
+
+    /// Check if `year` lies within the block
+    pub fn contains_year(&self, year: u32) -> bool {
+        self.first_year <= year && year <= self.last_year()
+    }
+
+    /// Check if `date` lies within the block
+    pub fn contains_date(&self, date: NaiveDate) -> bool {
+        u32::try_from(date.year()).is_ok_and(|year| self.contains_year(year))
+    }
+
+    /// Decompose the block into its years
+    pub fn decompose(&self) -> Vec<Year> {
+        (self.first_year..=self.last_year())
+            .filter_map(|year| Year::new(year).ok())
+            .collect()
+    }
+
+    /// Last year, `None` if past [`DatePeriod::MAX_YEAR`]
+    fn checked_last_year(&self) -> Option<u32> {
+        self.first_year
+            .checked_add(self.length_years - 1)
+            .filter(|year| *year <= DatePeriod::MAX_YEAR)
+    }
+}
+
+impl std::fmt::Display for YearBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.length_years {
+            10 if self.first_year.is_multiple_of(10) => write!(f, "{}X", self.first_year / 10),
+            100 if self.first_year.is_multiple_of(100) => write!(f, "{}XX", self.first_year / 100),
+            length => write!(f, "{}/{}Y", self.first_year, length),
+        }
+    }
+}
+
+impl std::str::FromStr for YearBlock {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || anyhow::anyhow!("Invalid year block format: {}", s);
+        let s = s.trim();
+        let number = |digits: &str| {
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            digits.parse::<u32>().map_err(|_| invalid())
+        };
+        if let Some((first_year, length)) = s.split_once('/') {
+            let length = length
+                .strip_suffix('Y')
+                .or_else(|| length.strip_suffix('y'))
+                .ok_or_else(invalid)?;
+            return Self::new(number(first_year)?, number(length)?);
+        }
+        let (prefix, length) = if let Some(prefix) = s.strip_suffix("XX") {
+            (prefix, 100)
+        } else if let Some(prefix) = s.strip_suffix('X') {
+            (prefix, 10)
+        } else {
+            return Err(invalid());
+        };
+        let first_year = number(prefix)?.checked_mul(length).ok_or_else(invalid)?;
+        Self::new(first_year, length)
+    }
+}
+
+impl Serialize for YearBlock {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for YearBlock {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<YearBlock, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use std::str::FromStr;
+        let s = String::deserialize(deserializer)?;
+        YearBlock::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Period for YearBlock {
+    type Parent = YearBlock;
+    type Child = Year;
+
+    fn get_first_day(&self) -> anyhow::Result<NaiveDate> {
+        Ok(YearBlock::get_first_day(self))
+    }
+
+    fn get_last_day(&self) -> anyhow::Result<NaiveDate> {
+        Ok(YearBlock::get_last_day(self))
+    }
+
+    fn checked_add(&self, n: i64) -> Option<Self> {
+        let first_year =
+            (self.first_year as i64).checked_add(n.checked_mul(self.length_years as i64)?)?;
+        let block = YearBlock {
+            first_year: u32::try_from(first_year).ok()?,
+            ..*self
+        };
+        block.checked_last_year().map(|_| block)
+    }
+
+    fn containing(&self, date: NaiveDate) -> anyhow::Result<Self> {
+        Self::from_date(date, self.length_years, self.first_year)
+    }
+
+    fn children(&self) -> Vec<Year> {
+        self.decompose()
+    }
+
+    fn contains_date(&self, date: NaiveDate) -> bool {
+        YearBlock::contains_date(self, date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::period;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_blocks_tile_the_years() {
+        for (length, anchor) in [(1, 0), (5, 2001), (10, 0), (10, 7), (100, 0)] {
+            let mut previous: Option<YearBlock> = None;
+            for year in 1900..2200 {
+                let block = YearBlock::from_year(year, length, anchor).unwrap();
+                assert!(block.contains_year(year));
+                assert_eq!(block.decompose().len() as u32, length);
+                assert_eq!(
+                    (block.first_year() as i64 - anchor as i64).rem_euclid(length as i64),
+                    0
+                );
+                if let Some(previous) = previous.filter(|p| *p != block) {
+                    assert_eq!(previous.succ().unwrap(), block);
+                    assert_eq!(block.first_year(), year);
+                }
+                previous = Some(block);
+            }
+        }
+        assert!(YearBlock::from_year(2024, 0, 0).is_err());
+        assert!(YearBlock::from_year(0, 5, 2001).is_err());
+    }
+
+    #[test]
+    fn test_aggregate_and_decompose() {
+        let year = Year::new(2024).unwrap();
+        let decade = year.decade().unwrap();
+        assert_eq!(decade, YearBlock::new(2020, 10).unwrap());
+        assert_eq!(year.century().unwrap(), YearBlock::new(2000, 100).unwrap());
+        assert_eq!(year.block(5, 0).unwrap(), YearBlock::new(2020, 5).unwrap());
+        let years = decade.decompose();
+        assert_eq!(years.first(), Some(&Year::new(2020).unwrap()));
+        assert_eq!(years.last(), Some(&Year::new(2029).unwrap()));
+        assert!(years.iter().all(|y| y.decade().unwrap() == decade));
+        assert_eq!(decade.children(), years);
+
+        assert_eq!(YearBlock::get_first_day(&decade), date(2020, 1, 1));
+        assert_eq!(YearBlock::get_last_day(&decade), date(2029, 12, 31));
+        assert!(decade.contains_date(date(2029, 12, 31)));
+        assert!(!decade.contains_date(date(2030, 1, 1)));
+    }
+
+    #[test]
+    fn test_navigation_and_ranges() {
+        let block = YearBlock::new(2020, 5).unwrap();
+        assert_eq!(
+            block.offset_n(-2).unwrap(),
+            YearBlock::new(2010, 5).unwrap()
+        );
+        assert_eq!(
+            block.containing(date(2031, 3, 1)).unwrap(),
+            YearBlock::new(2030, 5).unwrap()
+        );
+
+        let start = date(2018, 1, 1);
+        let end = date(2031, 1, 1);
+        let blocks = YearBlock::between(start, end, 5, 2000).unwrap();
+        assert_eq!(
+            blocks.iter().map(YearBlock::first_year).collect::<Vec<_>>(),
+            vec![2015, 2020, 2025, 2030]
+        );
+        let generic: Vec<_> = period::between(&blocks[0], start, end).unwrap().collect();
+        assert_eq!(generic, blocks);
+        assert!(YearBlock::between(end, start, 5, 2000).unwrap().is_empty());
+
+        let first = YearBlock::decade(0).unwrap();
+        assert!(first.pred().is_err());
+        let last = YearBlock::decade(262_139).unwrap();
+        assert!(last.succ().is_err());
+        assert!(YearBlock::decade(DatePeriod::MAX_YEAR).is_err());
+        assert_eq!(first.checked_add(i64::MAX), None);
+    }
+
+    #[test]
+    fn test_text_and_serde() {
+        let cases = [
+            (YearBlock::new(2020, 10).unwrap(), "202X"),
+            (YearBlock::new(0, 10).unwrap(), "0X"),
+            (YearBlock::new(1900, 100).unwrap(), "19XX"),
+            (YearBlock::new(2021, 10).unwrap(), "2021/10Y"),
+            (YearBlock::new(2020, 5).unwrap(), "2020/5Y"),
+        ];
+        for (block, text) in cases {
+            assert_eq!(block.to_string(), text);
+            assert_eq!(text.parse::<YearBlock>().unwrap(), block);
+            let json = serde_json::to_string(&block).unwrap();
+            assert_eq!(serde_json::from_str::<YearBlock>(&json).unwrap(), block);
+        }
+        assert_eq!("2020/10Y".parse::<YearBlock>().unwrap(), cases[0].0);
+        for invalid in [
+            "2020",
+            "X",
+            "2020/5",
+            "2020/0Y",
+            "-202X",
+            "20X2",
+            "+5XX",
+            "262142/2Y",
+        ] {
+            assert!(invalid.parse::<YearBlock>().is_err(), "{}", invalid);
+        }
+    }
+}
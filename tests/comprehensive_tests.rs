@@ -6,7 +6,7 @@ fn test_comprehensive_period_operations() {
     println!("=== Comprehensive Period Operations Test ===");
 
     // Test all period types creation
-    let year = DatePeriod::year(2024);
+    let year = DatePeriod::year(2024).unwrap();
     let quarter = DatePeriod::quarter(2024, 2).expect("Valid quarter");
     let month = DatePeriod::month(2024, 6).expect("Valid month");
     let daily = DatePeriod::daily(2024, 182).expect("Valid day"); // June 30th
@@ -74,17 +74,76 @@ fn test_string_parsing_edge_cases() {
     // Test with leading zeros
     let month_with_zero = DatePeriod::parse("2024M03").expect("Should parse M03");
     println!("Parsed M03: {}", month_with_zero);
-    assert_eq!(month_with_zero, DatePeriod::Month(2024, 3));
+    assert_eq!(month_with_zero, DatePeriod::month(2024, 3).unwrap());
 
     // Test daily with leading zeros
     let daily_with_zeros = DatePeriod::parse("2024D001").expect("Should parse D001");
     println!("Parsed D001: {}", daily_with_zeros);
-    assert_eq!(daily_with_zeros, DatePeriod::Daily(2024, 1));
+    assert_eq!(daily_with_zeros, DatePeriod::daily(2024, 1).unwrap());
 
     // Test invalid formats
     assert!(DatePeriod::parse("2024").is_err());
     assert!(DatePeriod::parse("2024X1").is_err());
-    assert!(DatePeriod::parse("24Q1").is_err()); // Year too short
+    assert!(DatePeriod::parse("Q1").is_err()); // Missing year
+    assert!(DatePeriod::parse("2024-3-01").is_err());
+
+    // Years are not padded, so short years parse as written
+    assert_eq!(
+        DatePeriod::parse("24Q1").unwrap(),
+        DatePeriod::quarter(24, 1).unwrap()
+    );
 
     println!("Invalid format rejection works correctly");
 }
+
+#[test]
+fn test_calendar_date_forms() {
+    println!("=== Calendar Date Forms ===");
+
+    let leap_day = DatePeriod::daily_from_ymd(2024, 2, 29).expect("Valid leap day");
+    assert_eq!(leap_day, DatePeriod::daily(2024, 60).unwrap());
+    assert_eq!(leap_day.get_month(), Some(2));
+    assert_eq!(leap_day.get_day_of_month(), Some(29));
+    assert_eq!(format!("{:#}", leap_day), "2024-02-29");
+    assert_eq!(leap_day.to_string(), "2024D60");
+
+    // Both calendar forms parse into the same daily period
+    assert_eq!(DatePeriod::parse("2024M2D29").unwrap(), leap_day);
+    assert_eq!(DatePeriod::parse("2024M02D29").unwrap(), leap_day);
+    assert_eq!(DatePeriod::parse("2024-02-29").unwrap(), leap_day);
+    assert_eq!(
+        DatePeriod::parse(&format!("{:#}", leap_day)).unwrap(),
+        leap_day
+    );
+
+    // Every day of a year round-trips through the alternate form
+    for day in DatePeriod::between_date_as_daily(
+        NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+        NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+    )
+    .unwrap()
+    {
+        assert_eq!(DatePeriod::parse(&format!("{:#}", day)).unwrap(), day);
+    }
+
+    // The alternate form only changes daily periods
+    assert_eq!(
+        format!("{:#}", DatePeriod::month(2024, 3).unwrap()),
+        "2024M3"
+    );
+
+    for invalid in [
+        "2023-02-29",
+        "2023M2D29",
+        "2024M13D1",
+        "2024M3D",
+        "2024-3-1",
+        "2024-03-01x",
+        "20é4Q1",
+        "2é24Q1",
+    ] {
+        assert!(DatePeriod::parse(invalid).is_err(), "{}", invalid);
+    }
+
+    println!("Calendar date forms work correctly");
+}
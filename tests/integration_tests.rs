@@ -6,7 +6,7 @@ use std::str::FromStr;
 fn test_constructor_examples() {
     println!("=== Constructor Examples ===");
 
-    let year_2024 = DatePeriod::year(2024);
+    let year_2024 = DatePeriod::year(2024).unwrap();
     let q2_2024 = DatePeriod::quarter(2024, 2).expect("Valid quarter");
     let may_2024 = DatePeriod::month(2024, 5).expect("Valid month");
     let day_136 = DatePeriod::daily(2024, 136).expect("Valid day");
@@ -32,8 +32,8 @@ fn test_parsing_from_strings() {
     println!("Parsed quarter: {}", parsed_quarter);
     println!("Parsed month: {}", parsed_month);
 
-    assert_eq!(parsed_quarter, DatePeriod::Quarter(2024, 3));
-    assert_eq!(parsed_month, DatePeriod::Month(2024, 12));
+    assert_eq!(parsed_quarter, DatePeriod::quarter(2024, 3).unwrap());
+    assert_eq!(parsed_month, DatePeriod::month(2024, 12).unwrap());
 }
 
 #[test]
@@ -42,20 +42,20 @@ fn test_date_conversion() {
 
     let date = NaiveDate::from_ymd_opt(2024, 8, 15).unwrap();
 
-    let as_year = DatePeriod::from_date_as_year(date);
-    let as_quarter = DatePeriod::from_date_as_quarter(date);
-    let as_month = DatePeriod::from_date_as_month(date);
-    let as_daily = DatePeriod::from_date_as_daily(date);
+    let as_year = DatePeriod::from_date_as_year(date).unwrap();
+    let as_quarter = DatePeriod::from_date_as_quarter(date).unwrap();
+    let as_month = DatePeriod::from_date_as_month(date).unwrap();
+    let as_daily = DatePeriod::from_date_as_daily(date).unwrap();
 
     println!("Date {} as year: {}", date, as_year);
     println!("Date {} as quarter: {}", date, as_quarter);
     println!("Date {} as month: {}", date, as_month);
     println!("Date {} as daily: {}", date, as_daily);
 
-    assert_eq!(as_year, DatePeriod::Year(2024));
-    assert_eq!(as_quarter, DatePeriod::Quarter(2024, 3)); // August is Q3
-    assert_eq!(as_month, DatePeriod::Month(2024, 8));
-    assert_eq!(as_daily, DatePeriod::Daily(2024, 228)); // 228th day of 2024
+    assert_eq!(as_year, DatePeriod::year(2024).unwrap());
+    assert_eq!(as_quarter, DatePeriod::quarter(2024, 3).unwrap()); // August is Q3
+    assert_eq!(as_month, DatePeriod::month(2024, 8).unwrap());
+    assert_eq!(as_daily, DatePeriod::daily(2024, 228).unwrap()); // 228th day of 2024
 }
 
 #[test]
@@ -139,3 +139,77 @@ fn test_validation_examples() {
     // All validation tests passed if we reach here
     println!("All validation tests completed successfully!");
 }
+
+#[test]
+fn test_round_trip_at_any_supported_year() {
+    for year in [0, 999, 10000, DatePeriod::MAX_YEAR] {
+        let last_day = if range_date::leap_year(year as i32) {
+            366
+        } else {
+            365
+        };
+        for period in [
+            DatePeriod::year(year).unwrap(),
+            DatePeriod::quarter(year, 4).unwrap(),
+            DatePeriod::month(year, 12).unwrap(),
+            DatePeriod::daily(year, last_day).unwrap(),
+        ] {
+            let text = period.to_string();
+            assert_eq!(DatePeriod::from_str(&text).unwrap(), period, "{}", text);
+            let json = serde_json::to_string(&period).unwrap();
+            assert_eq!(json, format!("\"{}\"", text));
+            assert_eq!(serde_json::from_str::<DatePeriod>(&json).unwrap(), period);
+        }
+    }
+    // The ISO form of daily periods round-trips too, without a year sign
+    for year in [0, 999, 2024, 10000, DatePeriod::MAX_YEAR] {
+        let day = DatePeriod::daily_from_ymd(year, 12, 31).unwrap();
+        let iso = format!("{:#}", day);
+        assert!(iso.ends_with("-12-31") && !iso.starts_with('+'), "{}", iso);
+        assert_eq!(DatePeriod::from_str(&iso).unwrap(), day, "{}", iso);
+    }
+    assert_eq!(
+        format!("{:#}", DatePeriod::daily(10000, 5).unwrap()),
+        "10000-01-05"
+    );
+    assert_eq!(
+        DatePeriod::from_str("999-02-28").unwrap(),
+        DatePeriod::daily_from_ymd(999, 2, 28).unwrap()
+    );
+    assert_eq!(DatePeriod::from_str("5M1").unwrap().to_string(), "5M1");
+}
+
+#[test]
+fn test_invalid_periods_are_unrepresentable() {
+    let too_late = DatePeriod::MAX_YEAR + 1;
+    assert!(DatePeriod::year(too_late).is_err());
+    assert!(DatePeriod::quarter(too_late, 1).is_err());
+    assert!(DatePeriod::month(too_late, 1).is_err());
+    assert!(DatePeriod::daily(too_late, 1).is_err());
+    assert!(DatePeriod::from_str(&format!("{}Y", too_late)).is_err());
+
+    // Deserialization goes through the same validation
+    for json in [
+        "\"2024Q5\"",
+        "\"2024M13\"",
+        "\"2023D366\"",
+        "\"2024D0\"",
+        "\"300000Y\"",
+    ] {
+        assert!(
+            serde_json::from_str::<DatePeriod>(json).is_err(),
+            "{}",
+            json
+        );
+    }
+
+    // Dates before year 0 have no period
+    let bce = NaiveDate::from_ymd_opt(-1, 12, 31).unwrap();
+    assert!(DatePeriod::from_date_as_year(bce).is_err());
+    assert!(DatePeriod::from_date_as_quarter(bce).is_err());
+    assert!(DatePeriod::from_date_as_month(bce).is_err());
+    assert!(DatePeriod::from_date_as_daily(bce).is_err());
+    assert!(
+        DatePeriod::between_date_as_month(bce, NaiveDate::from_ymd_opt(0, 2, 1).unwrap()).is_err()
+    );
+}